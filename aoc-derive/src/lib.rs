@@ -0,0 +1,217 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, LitStr, Member};
+
+/// Derives `FromStr`, with `aoc::error::Error` errors, from a format such as
+/// `#[aoc(format = "{x},{y},{z}")]`. Each field of the struct, named or numbered, appears
+/// once in the format, parsed with its own `FromStr` from the text up to the next literal
+/// part, less surrounding whitespace. Braces are escaped by doubling them.
+///
+/// Enum variants each have their own format, unit variants defaulting to their name,
+/// and the first variant to match is the result.
+#[proc_macro_derive(FromFormat, attributes(aoc))]
+pub fn derive_from_format(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_format(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn from_format(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let format = format_attr(&input.attrs)?
+                .ok_or_else(|| Error::new_spanned(&input.ident, "expected #[aoc(format = \"...\")]"))?;
+            let parse = parse_fields(&format, quote!(Self), &data.fields)?;
+            quote! {
+                let mut pattern = ::aoc::parse::Pattern::new(s);
+                #parse
+            }
+        }
+        Data::Enum(data) => {
+            let mut variants = Vec::new();
+            let mut formats = Vec::new();
+            for variant in &data.variants {
+                let ident = &variant.ident;
+                let format = match (format_attr(&variant.attrs)?, &variant.fields) {
+                    (Some(format), _) => format,
+                    (None, Fields::Unit) => LitStr::new(&ident.to_string(), ident.span()),
+                    (None, _) => return Err(Error::new_spanned(ident, "expected #[aoc(format = \"...\")]")),
+                };
+                let parse = parse_fields(&format, quote!(Self::#ident), &variant.fields)?;
+                variants.push(quote! {
+                    let variant = |s: &str| -> ::std::result::Result<Self, ::aoc::error::Error> {
+                        let mut pattern = ::aoc::parse::Pattern::new(s);
+                        #parse
+                    };
+                    if let ::std::result::Result::Ok(value) = variant(s) {
+                        return ::std::result::Result::Ok(value);
+                    }
+                });
+                formats.push(format);
+            }
+            quote! {
+                #(#variants)*
+                ::std::result::Result::Err(::aoc::parse::Pattern::no_match(s, &[#(#formats),*]))
+            }
+        }
+        Data::Union(_) => return Err(Error::new_spanned(&input.ident, "FromFormat can't be derived for unions")),
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::std::str::FromStr for #ident #ty_generics #where_clause {
+            type Err = ::aoc::error::Error;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                #body
+            }
+        }
+    })
+}
+
+fn format_attr(attrs: &[Attribute]) -> syn::Result<Option<LitStr>> {
+    let mut format = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("aoc")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("format") {
+                format = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `format = \"...\"`"))
+            }
+        })?;
+    }
+    Ok(format)
+}
+
+// Matches `pattern` against `format`, then builds `path` from the fields
+fn parse_fields(format: &LitStr, path: TokenStream2, fields: &Fields) -> syn::Result<TokenStream2> {
+    let pieces = pieces(&format.value()).map_err(|e| Error::new_spanned(format, e))?;
+    let members = fields.members().collect::<Vec<_>>();
+    let var = |member: &Member| match member {
+        Member::Named(ident) => format_ident!("field_{}", ident),
+        Member::Unnamed(index) => format_ident!("field_{}", index.index),
+    };
+
+    let mut steps = Vec::new();
+    let mut seen = Vec::new();
+    for (i, piece) in pieces.iter().enumerate() {
+        match piece {
+            Piece::Literal(literal) => steps.push(quote!(pattern.literal(#literal)?;)),
+            Piece::Field(name) => {
+                let member = members.iter()
+                    .find(|m| member_name(m) == *name)
+                    .ok_or_else(|| Error::new_spanned(format, format!("no field `{name}`")))?;
+                if seen.contains(&member) {
+                    return Err(Error::new_spanned(format, format!("field `{name}` appears more than once")));
+                }
+                seen.push(member);
+
+                let until = match pieces.get(i + 1) {
+                    Some(Piece::Literal(literal)) => quote!(::std::option::Option::Some(#literal)),
+                    _ => quote!(::std::option::Option::None),
+                };
+                let var = var(member);
+                steps.push(quote!(let #var = pattern.field(#until)?;));
+            }
+        }
+    }
+
+    if let Some(missing) = members.iter().find(|m| !seen.contains(m)) {
+        return Err(Error::new_spanned(format, format!("field `{}` is missing", member_name(missing))));
+    }
+
+    let vars = members.iter().map(var);
+    let value = match fields {
+        Fields::Named(_) => quote!(#path { #(#members: #vars),* }),
+        Fields::Unnamed(_) => quote!(#path(#(#vars),*)),
+        Fields::Unit => path,
+    };
+
+    Ok(quote! {
+        #(#steps)*
+        pattern.end()?;
+        ::std::result::Result::Ok(#value)
+    })
+}
+
+fn member_name(member: &Member) -> String {
+    match member {
+        Member::Named(ident) => ident.to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Piece {
+    Literal(String),
+    Field(String),
+}
+
+// Splits a format into literal text and `{field}`s, one literal between each pair of fields
+fn pieces(format: &str) -> Result<Vec<Piece>, String> {
+    let mut pieces = Vec::new();
+    let mut literal = String::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.as_str().starts_with('{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.as_str().starts_with('}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let (name, rest) = chars.as_str().split_once('}').ok_or("unclosed '{' in format")?;
+                if name.is_empty() {
+                    return Err("empty field name in format".to_owned());
+                }
+                if !literal.is_empty() {
+                    pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                } else if matches!(pieces.last(), Some(Piece::Field(_))) {
+                    return Err(format!("field `{name}` must be separated from the one before"));
+                }
+                pieces.push(Piece::Field(name.trim().to_owned()));
+                chars = rest.chars();
+            }
+            '}' => return Err("unmatched '}' in format (use '}}')".to_owned()),
+            c => literal.push(c),
+        }
+    }
+
+    if !literal.is_empty() {
+        pieces.push(Piece::Literal(literal));
+    }
+    Ok(pieces)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lit(s: &str) -> Piece {
+        Piece::Literal(s.to_owned())
+    }
+
+    fn field(s: &str) -> Piece {
+        Piece::Field(s.to_owned())
+    }
+
+    #[test]
+    fn format_pieces() {
+        assert_eq!(pieces("{x},{y}~{z}"), Ok(vec![field("x"), lit(","), field("y"), lit("~"), field("z")]));
+        assert_eq!(pieces("{name}{{{0}}}"), Ok(vec![field("name"), lit("{"), field("0"), lit("}")]));
+        assert_eq!(pieces("R"), Ok(vec![lit("R")]));
+        assert_eq!(pieces(""), Ok(vec![]));
+
+        assert!(pieces("{x}{y}").is_err());
+        assert!(pieces("{x").is_err());
+        assert!(pieces("x}").is_err());
+        assert!(pieces("{}").is_err());
+    }
+}
@@ -0,0 +1,245 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+
+use aoc::bench::{bench, write_report, PhaseTimes};
+use aoc::error::{aoc_err, Error};
+use aoc::frames::{record, save_frames, Frame};
+use aoc::input::{InputConfig, InputSource};
+use aoc::solution::Puzzle;
+use aoc::verify::{verify, Answers};
+use aoc::viewer::view;
+
+use crate::puzzles::puzzle;
+use crate::report::{Format, Record, Reporter};
+use crate::select::DaySelection;
+
+mod puzzles;
+mod report;
+mod select;
+
+/// Run Advent of Code 2023 solutions
+#[derive(Debug, Parser)]
+#[command(version)]
+struct Args {
+    /// Days to run: a day number (`7` or `day-07`), a range (`3-9`) or `all`
+    #[arg(default_value = "all")]
+    days: Vec<DaySelection>,
+
+    /// Input file for a single day, or `-` for stdin [default: $AOC_INPUT or data/day-NN.txt]
+    #[arg(short, long)]
+    input: Option<InputSource>,
+
+    /// Directory of `day-NN.txt` inputs [default: $AOC_DATA_DIR or the nearest `data`]
+    #[arg(long)]
+    data_dir: Option<PathBuf>,
+
+    /// Check answers against those recorded in `data/day-NN.answers`
+    #[arg(long, conflicts_with = "bench")]
+    verify: bool,
+
+    /// Time parsing and each part over repeated runs
+    #[arg(long)]
+    bench: bool,
+
+    /// Number of runs per phase when benchmarking
+    #[arg(long, default_value_t = 10, requires = "bench")]
+    runs: usize,
+
+    /// Write benchmark timings to this CSV file
+    #[arg(long, requires = "bench")]
+    report: Option<PathBuf>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Record simulation frames from each part to this `.txt`, `.gif` or `.png` (APNG) file,
+    /// with `-day-NN-partP` added to the name
+    #[arg(long, conflicts_with_all = ["verify", "bench"])]
+    frames: Option<PathBuf>,
+
+    /// Step through each part's recorded frames in the terminal
+    #[arg(long, conflicts_with_all = ["verify", "bench", "format"])]
+    view: bool,
+
+    /// Most frames to record per part
+    #[arg(long, default_value_t = 1000)]
+    frame_limit: usize,
+
+    /// Pixels to a grid cell in animated frames
+    #[arg(long, default_value_t = 4, requires = "frames")]
+    frame_scale: usize,
+
+    /// Milliseconds between animated or played frames
+    #[arg(long, default_value_t = 100)]
+    frame_delay: u64,
+}
+
+/// Where and how to save or show recorded frames
+struct FramesConfig {
+    path: Option<PathBuf>,
+    view: bool,
+    limit: usize,
+    scale: usize,
+    delay: Duration,
+}
+
+impl FramesConfig {
+    fn path_for(path: &Path, day: u8, part: u8) -> PathBuf {
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        let ext = path.extension().unwrap_or_default().to_string_lossy();
+        path.with_file_name(format!("{stem}-day-{day:02}-part{part}.{ext}"))
+    }
+
+    fn save(&self, day: u8, part: u8, frames: &[Frame]) -> Result<(), Error> {
+        if frames.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(path) = &self.path {
+            let path = Self::path_for(path, day, part);
+            save_frames(&path, frames, self.scale, self.delay).map_err(|e| aoc_err(format!("{}: {e}", path.display())))?;
+        }
+
+        if self.view {
+            view(frames, self.delay)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn find_puzzle(day: u8) -> Result<&'static dyn Puzzle, Error> {
+    puzzle(day).ok_or_else(|| aoc_err(format!("day-{day:02} is not built")))
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+fn run_day(day: u8, config: &InputConfig, frames: Option<&FramesConfig>, out: &mut Reporter) -> Result<(), Error> {
+    let puzzle = find_puzzle(day)?;
+    let source = config.resolve(puzzle)?;
+    let input = source.read()?;
+
+    let (parsed, elapsed) = out.phase(day, None, || timed(|| puzzle.parse(&input)));
+    let parsed = parsed.map_err(|e| e.in_source(&source.name()))?;
+    out.report(day, Record::Parsed { elapsed });
+
+    for part in 1..=puzzle.parts() {
+        let run = || timed(|| puzzle.part(part, parsed.as_ref()));
+        let (answer, elapsed) = match frames {
+            None => out.phase(day, Some(part), run),
+            Some(frames) => {
+                let (result, recorded) = out.phase(day, Some(part), || record(frames.limit, run));
+                frames.save(day, part, &recorded)?;
+                result
+            }
+        };
+        out.report(day, Record::Answer { part, answer: &answer?, elapsed });
+    }
+
+    Ok(())
+}
+
+/// Returns whether every part passed or has no recorded answer
+fn verify_day(day: u8, config: &InputConfig, out: &mut Reporter) -> Result<bool, Error> {
+    let puzzle = find_puzzle(day)?;
+    let source = config.resolve(puzzle)?;
+    let input = source.read()?;
+    let name = source.name();
+    let answers_for = match source {
+        InputSource::File(path) => Some(path),
+        InputSource::Builtin(_) => config.default_path(puzzle).ok(),
+        InputSource::Stdin => None,
+    };
+    let answers = match answers_for {
+        Some(path) => Answers::read_for(&path)?.unwrap_or_default(),
+        None => Answers::default(),
+    };
+
+    let mut ok = true;
+    let verdicts = out.phase(day, None, || verify(puzzle, &input, &answers)).map_err(|e| e.in_source(&name))?;
+    for (part, verdict) in verdicts {
+        out.report(day, Record::Verdict { part, verdict: &verdict });
+        ok &= verdict.is_ok();
+    }
+
+    Ok(ok)
+}
+
+fn bench_day(day: u8, runs: usize, config: &InputConfig, out: &mut Reporter)
+    -> Result<(&'static dyn Puzzle, Vec<PhaseTimes>), Error>
+{
+    let puzzle = find_puzzle(day)?;
+    let source = config.resolve(puzzle)?;
+    let input = source.read()?;
+    let times = out.phase(day, None, || bench(puzzle, &input, runs)).map_err(|e| e.in_source(&source.name()))?;
+    for t in &times {
+        out.report(day, Record::Bench { times: t });
+    }
+
+    Ok((puzzle, times))
+}
+
+fn write_bench_report(path: &Path, results: &[(&dyn Puzzle, Vec<PhaseTimes>)]) -> Result<(), Error> {
+    let names = results.iter().map(|(puzzle, _)| puzzle.name()).collect::<Vec<_>>();
+    let rows = names.iter().zip(results).map(|(name, (_, times))| (name.as_str(), times.as_slice()));
+    write_report(BufWriter::new(File::create(path)?), rows)
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let mut config = InputConfig::from_env();
+    config.source = args.input.or(config.source);
+    config.data_dir = args.data_dir.or(config.data_dir);
+
+    let days = args.days.iter().flat_map(DaySelection::days).collect::<Vec<_>>();
+    if config.source.is_some() && days.len() != 1 {
+        eprintln!("An input file can only be given for a single day");
+        return ExitCode::FAILURE;
+    }
+
+    let frames = (args.frames.is_some() || args.view).then(|| FramesConfig {
+        path: args.frames,
+        view: args.view,
+        limit: args.frame_limit,
+        scale: args.frame_scale,
+        delay: Duration::from_millis(args.frame_delay),
+    });
+
+    let mut out = Reporter::new(args.format);
+    let mut failed = false;
+    let mut bench_results = Vec::new();
+    for day in days {
+        let result = if args.verify {
+            verify_day(day, &config, &mut out).map(|ok| failed |= !ok)
+        } else if args.bench {
+            bench_day(day, args.runs, &config, &mut out).map(|result| bench_results.push(result))
+        } else {
+            run_day(day, &config, frames.as_ref(), &mut out)
+        };
+
+        if let Err(error) = result {
+            out.report(day, Record::Error { error: &error });
+            failed = true;
+        }
+    }
+
+    if let Some(path) = &args.report {
+        if let Err(e) = write_bench_report(path, &bench_results) {
+            eprintln!("{}: {e}", path.display());
+            failed = true;
+        }
+    }
+
+    if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
@@ -0,0 +1,57 @@
+use aoc::solution::Puzzle;
+
+pub const PUZZLES: &[&dyn Puzzle] = &[
+    &day_01::Day01,
+    &day_02::Day02,
+    &day_03::Day03,
+    &day_04::Day04,
+    &day_05::Day05,
+    &day_06::Day06,
+    &day_07::Day07,
+    &day_08::Day08,
+    &day_09::Day09,
+    &day_10::Day10,
+    &day_11::Day11,
+    &day_12::Day12,
+    &day_13::Day13,
+    &day_14::Day14,
+    &day_15::Day15,
+    &day_16::Day16,
+    &day_17::Day17,
+    &day_18::Day18,
+    &day_19::Day19,
+    &day_20::Day20,
+    &day_21::Day21,
+    &day_22::Day22,
+    &day_23::Day23,
+    #[cfg(feature = "day-24")]
+    &day_24::Day24,
+    &day_25::Day25,
+];
+
+pub fn puzzle(day: u8) -> Option<&'static dyn Puzzle> {
+    PUZZLES.iter().copied().find(|p| p.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use aoc::verify::verify_dir;
+
+    use super::*;
+
+    #[test]
+    fn regression_inputs() {
+        let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let mut failures = Vec::new();
+        for puzzle in PUZZLES {
+            let dir = workspace.join(puzzle.name()).join("inputs");
+            if dir.is_dir() {
+                let day_failures = verify_dir(*puzzle, &dir).unwrap_or_else(|e| panic!("{}: {e}", dir.display()));
+                failures.extend(day_failures.into_iter().map(|failure| format!("{}: {failure}", puzzle.name())));
+            }
+        }
+        assert!(failures.is_empty(), "Failed:\n{}", failures.join("\n"));
+    }
+}
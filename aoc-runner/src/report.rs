@@ -0,0 +1,162 @@
+use std::io::{self, StdoutLock, Write};
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde_json::{json, Value};
+
+use aoc::bench::{Phase, PhaseTimes};
+use aoc::diag::{capture, Event};
+use aoc::error::Error;
+use aoc::verify::Verdict;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human-readable lines, with diagnostics on stderr
+    Text,
+    /// One JSON object per line, including diagnostics
+    Json,
+}
+
+/// Something that happened while running a day
+#[derive(Debug)]
+pub enum Record<'a> {
+    Parsed { elapsed: Duration },
+    Answer { part: u8, answer: &'a str, elapsed: Duration },
+    Verdict { part: u8, verdict: &'a Verdict },
+    Bench { times: &'a PhaseTimes },
+    Event { part: Option<u8>, event: &'a Event },
+    Error { error: &'a Error },
+}
+
+pub struct Reporter {
+    format: Format,
+    out: StdoutLock<'static>,
+}
+
+fn nanos(d: Duration) -> u64 {
+    d.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+fn phase_part(phase: Phase) -> Option<u8> {
+    match phase {
+        Phase::Parse => None,
+        Phase::Part(part) => Some(part),
+    }
+}
+
+impl Reporter {
+    pub fn new(format: Format) -> Self {
+        Self { format, out: io::stdout().lock() }
+    }
+
+    /// Runs `f` for one phase of `day`. Diagnostics it emits are reported as
+    /// events in JSON, or left to go straight to stderr as text.
+    pub fn phase<T>(&mut self, day: u8, part: Option<u8>, f: impl FnOnce() -> T) -> T {
+        match self.format {
+            Format::Text => f(),
+            Format::Json => {
+                let (result, events) = capture(f);
+                for event in &events {
+                    self.report(day, Record::Event { part, event });
+                }
+                result
+            }
+        }
+    }
+
+    pub fn report(&mut self, day: u8, record: Record) {
+        let result = match self.format {
+            Format::Text => self.text(day, record),
+            Format::Json => writeln!(self.out, "{}", json_record(day, record)),
+        };
+
+        // Nowhere left to report to, e.g. stdout closed by a pipe
+        if let Err(e) = result {
+            eprintln!("Can't write output: {e}");
+        }
+    }
+
+    fn text(&mut self, day: u8, record: Record) -> io::Result<()> {
+        let name = format!("day-{day:02}");
+        match record {
+            Record::Parsed { .. } => Ok(()),
+            Record::Answer { part, answer, .. } => writeln!(self.out, "{name} part {part}: {answer}"),
+            Record::Verdict { part, verdict } => writeln!(self.out, "{name} part {part}: {verdict}"),
+            Record::Bench { times: t } => writeln!(self.out, "{name} {}: median {:.2?} (min {:.2?}, max {:.2?}, {} runs)",
+                                                   t.phase, t.median(), t.min(), t.max(), t.runs()),
+            Record::Event { event, .. } => {
+                eprintln!("{name}: {event}");
+                Ok(())
+            }
+            Record::Error { error } => {
+                eprintln!("{name}: {error}");
+                Ok(())
+            }
+        }
+    }
+}
+
+fn json_record(day: u8, record: Record) -> Value {
+    match record {
+        Record::Parsed { elapsed } => json!({
+            "type": "parse", "day": day, "time_ns": nanos(elapsed),
+        }),
+        Record::Answer { part, answer, elapsed } => json!({
+            "type": "answer", "day": day, "part": part, "answer": answer, "time_ns": nanos(elapsed),
+        }),
+        Record::Verdict { part, verdict } => {
+            let mut value = json!({ "type": "verify", "day": day, "part": part });
+            let fields = match verdict {
+                Verdict::Pass => json!({ "result": "pass" }),
+                Verdict::Mismatch { expected, actual } => json!({ "result": "mismatch", "expected": expected, "answer": actual }),
+                Verdict::Fail(e) => json!({ "result": "fail", "error": e.to_string() }),
+                Verdict::Panicked(message) => json!({ "result": "panic", "error": message }),
+                Verdict::Unverified { actual } => json!({ "result": "unverified", "answer": actual }),
+            };
+            if let (Some(value), Value::Object(fields)) = (value.as_object_mut(), fields) {
+                value.extend(fields);
+            }
+            value
+        }
+        Record::Bench { times: t } => json!({
+            "type": "bench", "day": day, "part": phase_part(t.phase), "runs": t.runs(),
+            "median_ns": nanos(t.median()), "min_ns": nanos(t.min()), "max_ns": nanos(t.max()),
+        }),
+        Record::Event { part, event } => json!({
+            "type": "event", "day": day, "part": part, "source": event.source, "message": event.message,
+        }),
+        Record::Error { error } => {
+            let mut value = json!({ "type": "error", "day": day, "message": error.to_string() });
+            if let Some(location) = error.location() {
+                value["source"] = json!(location.source);
+                value["line"] = json!(location.line);
+                value["column"] = json!(location.column());
+            }
+            value
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::error::aoc_err;
+
+    #[test]
+    fn json_records() {
+        let record = Record::Answer { part: 2, answer: "54265", elapsed: Duration::from_micros(3) };
+        assert_eq!(json_record(1, record).to_string(),
+                   r#"{"answer":"54265","day":1,"part":2,"time_ns":3000,"type":"answer"}"#);
+
+        let verdict = Verdict::Mismatch { expected: "4".to_owned(), actual: "3".to_owned() };
+        assert_eq!(json_record(7, Record::Verdict { part: 1, verdict: &verdict }).to_string(),
+                   r#"{"answer":"3","day":7,"expected":"4","part":1,"result":"mismatch","type":"verify"}"#);
+
+        let event = Event { source: "aoc::cycle", message: "Cycle at 3 -> 10, need 5".to_owned() };
+        assert_eq!(json_record(14, Record::Event { part: Some(2), event: &event }).to_string(),
+                   r#"{"day":14,"message":"Cycle at 3 -> 10, need 5","part":2,"source":"aoc::cycle","type":"event"}"#);
+
+        let error = aoc_err("No start nodes");
+        assert_eq!(json_record(8, Record::Error { error: &error })["type"], "error");
+    }
+}
@@ -0,0 +1,86 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use aoc::error::{aoc_err, Error};
+
+pub const FIRST_DAY: u8 = 1;
+pub const LAST_DAY: u8 = 25;
+
+/// Days chosen on the command line: `7`, `day-07`, `3-9` or `all`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection(RangeInclusive<u8>);
+
+impl DaySelection {
+    pub fn all() -> Self {
+        Self(FIRST_DAY..=LAST_DAY)
+    }
+
+    pub fn days(&self) -> RangeInclusive<u8> {
+        self.0.clone()
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, Error> {
+    let day: u8 = s.parse()?;
+    if !(FIRST_DAY..=LAST_DAY).contains(&day) {
+        return Err(aoc_err(format!("Day {day} out of range {FIRST_DAY}-{LAST_DAY}")));
+    }
+
+    Ok(day)
+}
+
+impl FromStr for DaySelection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Self::all());
+        }
+
+        let days = s.replace("day-", "");
+        let (start, end) = match days.split_once("..").or_else(|| days.split_once('-')) {
+            Some((start, end)) => (parse_day(start)?, parse_day(end)?),
+            None => {
+                let day = parse_day(&days)?;
+                (day, day)
+            }
+        };
+
+        if start > end {
+            return Err(aoc_err(format!("Empty day range {s}")));
+        }
+
+        Ok(Self(start..=end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn days(s: &str) -> Vec<u8> {
+        s.parse::<DaySelection>().unwrap().days().collect()
+    }
+
+    #[test]
+    fn single_day() {
+        assert_eq!(days("7"), [7]);
+        assert_eq!(days("day-07"), [7]);
+        assert_eq!(days("day-25"), [25]);
+    }
+
+    #[test]
+    fn day_range() {
+        assert_eq!(days("3-5"), [3, 4, 5]);
+        assert_eq!(days("day-03-day-05"), [3, 4, 5]);
+        assert_eq!(days("24..25"), [24, 25]);
+        assert_eq!(days("all").len(), 25);
+    }
+
+    #[test]
+    fn bad_selection() {
+        for s in ["0", "26", "5-3", "day-", "x", "1-"] {
+            assert!(s.parse::<DaySelection>().is_err(), "{s}");
+        }
+    }
+}
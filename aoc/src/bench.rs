@@ -0,0 +1,135 @@
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+use crate::error::{aoc_err, Error};
+use crate::solution::Puzzle;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Part(u8),
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+/// Wall clock times for repeated runs of one phase
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhaseTimes {
+    pub phase: Phase,
+    // Sorted ascending
+    samples: Vec<Duration>,
+}
+
+impl PhaseTimes {
+    pub fn new(phase: Phase, mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "No samples for {phase}");
+        samples.sort_unstable();
+        Self { phase, samples }
+    }
+
+    pub fn runs(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+
+    pub fn median(&self) -> Duration {
+        let n = self.samples.len();
+        if n % 2 == 1 {
+            self.samples[n / 2]
+        } else {
+            (self.samples[n / 2 - 1] + self.samples[n / 2]) / 2
+        }
+    }
+}
+
+fn time<T>(runs: usize, mut f: impl FnMut() -> Result<T, Error>) -> Result<Vec<Duration>, Error> {
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        let result = black_box(f()?);
+        samples.push(start.elapsed());
+        drop(result);
+    }
+
+    Ok(samples)
+}
+
+/// Times `runs` repetitions each of parsing `input` and solving every part of `puzzle`.
+pub fn bench(puzzle: &dyn Puzzle, input: &str, runs: usize) -> Result<Vec<PhaseTimes>, Error> {
+    if runs == 0 {
+        return Err(aoc_err("Benchmark needs at least one run"));
+    }
+
+    let mut result = vec![PhaseTimes::new(Phase::Parse, time(runs, || puzzle.parse(black_box(input)))?)];
+    let parsed = puzzle.parse(input)?;
+    for part in 1..=puzzle.parts() {
+        let samples = time(runs, || puzzle.part(part, black_box(parsed.as_ref())))?;
+        result.push(PhaseTimes::new(Phase::Part(part), samples));
+    }
+
+    Ok(result)
+}
+
+/// Writes timings as CSV, one row per puzzle and phase, in nanoseconds.
+pub fn write_report<'a, W, I>(mut w: W, results: I) -> Result<(), Error>
+    where W: Write, I: IntoIterator<Item=(&'a str, &'a [PhaseTimes])>
+{
+    writeln!(w, "puzzle,phase,runs,median_ns,min_ns,max_ns")?;
+    for (name, times) in results {
+        for t in times {
+            writeln!(w, "{name},{},{},{},{},{}",
+                     t.phase, t.runs(), t.median().as_nanos(), t.min().as_nanos(), t.max().as_nanos())?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: &[u64]) -> Vec<Duration> {
+        millis.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn stats() {
+        let times = PhaseTimes::new(Phase::Part(1), ms(&[5, 1, 9, 3]));
+        assert_eq!(times.runs(), 4);
+        assert_eq!(times.min(), Duration::from_millis(1));
+        assert_eq!(times.max(), Duration::from_millis(9));
+        assert_eq!(times.median(), Duration::from_millis(4));
+
+        let times = PhaseTimes::new(Phase::Parse, ms(&[7, 2, 3]));
+        assert_eq!(times.median(), Duration::from_millis(3));
+    }
+
+    #[test]
+    fn report() {
+        let times = [PhaseTimes::new(Phase::Parse, ms(&[2])), PhaseTimes::new(Phase::Part(2), ms(&[1, 3]))];
+        let mut out = Vec::new();
+        write_report(&mut out, [("day-01", &times[..])]).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), concat!(
+            "puzzle,phase,runs,median_ns,min_ns,max_ns\n",
+            "day-01,parse,1,2000000,2000000,2000000\n",
+            "day-01,part 2,2,2000000,1000000,3000000\n",
+        ));
+    }
+}
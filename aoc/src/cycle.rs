@@ -1,177 +1,272 @@
+use std::collections::HashMap;
 use std::hash::Hash;
-use indexmap::IndexMap;
-use indexmap::map::Entry;
+use std::ops::{Add, Mul, Sub};
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub struct NoCycle<T> {
-    pub target: (usize, T),
-}
+use crate::error::{aoc_err, no_solution, Error};
+use crate::frames;
 
+/// Where a sequence of states repeats: the state at `start + len` is the one at `start`
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub struct Cycle<T> {
-    pub start: (usize, T),
-    pub end: (usize, T),
-    pub target_equiv: (usize, T),
-    pub target: usize,
+pub struct Cycle {
+    pub start: usize,
+    pub len: usize,
 }
 
-impl<T> Cycle<T> {
-    pub fn cycle_len(&self) -> usize {
-        self.end.0 - self.start.0
+impl Cycle {
+    /// The first index with the same state as index `i`
+    pub fn equivalent(&self, i: usize) -> usize {
+        if i < self.start { i } else { self.start + (i - self.start) % self.len }
     }
 
-    pub fn complete_cycles(&self) -> usize {
-        (self.target - self.start.0) / self.cycle_len()
+    /// Number of times round the whole cycle on the way to index `i`
+    pub fn complete_cycles(&self, i: usize) -> usize {
+        i.saturating_sub(self.start) / self.len
     }
 }
 
-fn swap_remove_or_insert<K: Eq + Hash, V>(map: &mut IndexMap<K, V>, key: K, value: V) -> Option<(usize, V, V)> {
-    match map.entry(key) {
-        Entry::Occupied(e) => {
-            let index = e.index();
-            let (_, existing_val) = map.swap_remove_index(index).unwrap();
-            Some((index, existing_val, value))
+enum Search<S> {
+    Reached(S),
+    Found(Cycle),
+}
+
+// Brent's algorithm: the hare steps on alone, and the tortoise jumps to it at each
+// power of two, so the cycle length is found once the tortoise is inside the cycle.
+// Stops with the hare's state if it takes `limit` steps first. Only the hare's steps
+// are passed to `seen`, these being the first `limit` steps in order, and only they
+// can record frames.
+fn search<S, A, F, G>(start: &S, step: &mut F, limit: usize, mut seen: G) -> Search<S>
+    where
+        S: Clone + Eq,
+        F: FnMut(&mut S) -> A,
+        G: FnMut(A),
+{
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    let (mut power, mut len) = (1, 0);
+    for steps in 0.. {
+        if steps == limit {
+            return Search::Reached(hare);
+        }
+
+        seen(step(&mut hare));
+        len += 1;
+        if tortoise == hare {
+            break;
         }
-        Entry::Vacant(e) => {
-            e.insert(value);
-            None
+        if len == power {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
         }
     }
+
+    // With the hare `len` ahead, they first meet at the start of the cycle
+    let cycle_start = frames::unrecorded(|| {
+        let mut tortoise = start.clone();
+        let mut hare = start.clone();
+        for _ in 0..len {
+            step(&mut hare);
+        }
+        let mut cycle_start = 0;
+        while tortoise != hare {
+            step(&mut tortoise);
+            step(&mut hare);
+            cycle_start += 1;
+        }
+        cycle_start
+    });
+
+    Search::Found(Cycle { start: cycle_start, len })
 }
 
-pub fn find_in_cycle<I, K, V>(iter: I, target: usize) -> Result<Cycle<V>, NoCycle<V>>
+/// Finds the first repeated state reached from `start` by `step`, failing if that takes
+/// more than `limit` steps. The search needs up to about `start + 2 * len` steps for a
+/// [`Cycle`] of `len` states from `start`, but holds only a couple of states at once.
+pub fn find_cycle<S, A, F>(start: &S, mut step: F, limit: usize) -> Result<Cycle, Error>
     where
-        I: IntoIterator<Item=(K, V)>,
-        K: Eq + Hash,
-        V: Clone,
+        S: Clone + Eq,
+        F: FnMut(&mut S) -> A,
 {
-    let mut seen: IndexMap<K, V> = IndexMap::new();
-    let (cycle_start, cycle_start_val, cycle_end, cycle_end_val) = iter.into_iter()
-        .take(target + 1)
-        .enumerate()
-        .filter_map(|(j, (key, val_j))|
-            swap_remove_or_insert(&mut seen, key, val_j)
-                .map(|(i, val_i, val_j)| (i, val_i, j, val_j))
-        )
-        .next()
-        .ok_or_else(|| {
-            let (_, val) = seen.swap_remove_index(target).expect("Iterator too short for target index");
-            NoCycle { target: (target, val) }
-        })?;
-
-    let cycle_len = cycle_end - cycle_start;
-    let target_equiv = ((target - cycle_start) % cycle_len) + cycle_start;
-    eprintln!("Cycle at {cycle_start:?} -> {cycle_end:?}, need {target_equiv:?}");
-    assert_eq!(cycle_end, seen.len() + 1);
-    assert!(target_equiv < cycle_end);
-
-    let target_equiv_val = if target_equiv == cycle_start {
-        cycle_start_val.clone()
-    } else {
-        // If target_equiv was at the end, it was swap_removed with cycle_start
-        let seen_ix = if target_equiv == seen.len() { cycle_start } else { target_equiv };
-        seen.swap_remove_index(seen_ix).unwrap().1
-    };
+    match search(start, &mut step, limit, drop) {
+        Search::Found(cycle) => Ok(cycle),
+        Search::Reached(_) => Err(no_solution(format!("No cycle in {limit} steps"))),
+    }
+}
 
-    Ok(Cycle {
-        start: (cycle_start, cycle_start_val),
-        end: (cycle_end, cycle_end_val),
-        target_equiv: (target_equiv, target_equiv_val),
-        target: target,
-    })
+/// The state after `n` steps from `start`, skipping round any cycle
+pub fn nth_state<S, A, F>(start: S, mut step: F, n: usize) -> S
+    where
+        S: Clone + Eq,
+        F: FnMut(&mut S) -> A,
+{
+    match search(&start, &mut step, n, drop) {
+        Search::Reached(state) => state,
+        Search::Found(cycle) => frames::unrecorded(|| {
+            let mut state = start;
+            for _ in 0..cycle.equivalent(n) {
+                step(&mut state);
+            }
+            state
+        }),
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// The state after `n` steps from `start`, and the sum of the values returned by those
+/// steps, multiplying up the sum round any cycle rather than stepping round it
+pub fn extrapolate<S, A, F>(start: S, mut step: F, n: usize) -> (S, A)
+    where
+        S: Clone + Eq,
+        A: Clone + Default + Add<Output=A> + Mul<usize, Output=A>,
+        F: FnMut(&mut S) -> A,
+{
+    let mut total = A::default();
+    let cycle = match search(&start, &mut step, n, |a| total = std::mem::take(&mut total) + a) {
+        Search::Reached(state) => return (state, total),
+        Search::Found(cycle) => cycle,
+    };
 
-    const TEST: [(i32, char); 10] = [
-        (0, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (0, 'e'), (1, 'f'), (2, 'g'), (3, 'h'), (0, 'i'), (1, 'j')
-    ];
+    // Prefix, then once round the cycle noting where `n` falls in it
+    frames::unrecorded(|| {
+        let mut state = start;
+        let mut prefix = A::default();
+        for _ in 0..cycle.start {
+            prefix = prefix + step(&mut state);
+        }
+        let remainder = cycle.equivalent(n) - cycle.start;
+        let mut lap = A::default();
+        let mut target = None;
+        for i in 0..cycle.len {
+            if i == remainder {
+                target = Some((state.clone(), lap.clone()));
+            }
+            lap = lap + step(&mut state);
+        }
+        let (target, partial) = target.expect("Remainder within the cycle");
 
-    const OFFSET_TEST: [(i32, char); 10] = [
-        (-2, 'z'), (-1, 'y'), (0, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (0, 'e'), (1, 'f'), (2, 'g'), (3, 'h')
-    ];
+        (target, prefix + lap * cycle.complete_cycles(n) + partial)
+    })
+}
 
-    #[test]
-    #[should_panic]
-    fn empty() {
-        let _ = find_in_cycle(Vec::<(i32, i32)>::new(), 0);
-    }
+/// The sum of the first `n` contributions from `steps`, each a state with the contribution
+/// of the step on from it. Once a state repeats, the total is extrapolated from the totals
+/// at its two visits. For steps that can't be replayed from a cloned state, at the cost of
+/// remembering each state until the cycle is found; otherwise see [`extrapolate`].
+pub fn accumulate<I, S, A>(steps: I, n: usize) -> Result<A, Error>
+    where
+        I: IntoIterator<Item=(S, A)>,
+        S: Eq + Hash,
+        A: Clone + Default + Add<Output=A> + Sub<Output=A> + Mul<usize, Output=A>,
+{
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    // Total of the contributions before each index
+    let mut totals = vec![A::default()];
+    for (i, (state, contribution)) in steps.into_iter().enumerate() {
+        if i == n {
+            break;
+        }
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle { start, len: i - start };
+            let lap = totals[i].clone() - totals[start].clone();
+            return Ok(totals[cycle.equivalent(n)].clone() + lap * cycle.complete_cycles(n));
+        }
 
-    #[test]
-    #[should_panic]
-    fn too_short() {
-        let _ = find_in_cycle(vec![(0, 0)], 1);
+        seen.insert(state, i);
+        totals.push(totals[i].clone() + contribution);
     }
 
-    #[test]
-    fn no_cycle_0() {
-        let actual = find_in_cycle(TEST, 0);
-        let expected = Err(NoCycle { target: (0, 'a') });
-        assert_eq!(actual, expected);
+    let len = totals.len() - 1;
+    if len < n {
+        return Err(aoc_err(format!("Steps ended after {len}, short of {n}")));
     }
+    Ok(totals.swap_remove(n))
+}
 
-    #[test]
-    fn no_cycle_3() {
-        let actual = find_in_cycle(TEST, 3);
-        let expected = Err(NoCycle { target: (3, 'd') });
-        assert_eq!(actual, expected);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    #[test]
-    fn cycle_4_4() {
-        let actual = find_in_cycle(TEST, 4);
-        let expected = Ok(Cycle { start: (0, 'a'), end: (4, 'e'), target_equiv: (0, 'a'), target: 4 });
-        assert_eq!(actual, expected);
+    // 0, 1, 2, 3, then round 4..=9
+    fn step(i: &mut u32) -> u32 {
+        let value = *i;
+        *i = if *i == 9 { 4 } else { *i + 1 };
+        value
     }
 
     #[test]
-    fn cycle_4_5() {
-        let actual = find_in_cycle(TEST, 5);
-        let expected = Ok(Cycle { start: (0, 'a'), end: (4, 'e'), target_equiv: (1, 'b'), target: 5 });
-        assert_eq!(actual, expected);
-    }
+    fn cycles() {
+        let cycle = find_cycle(&0, step, 100).unwrap();
+        assert_eq!(cycle, Cycle { start: 4, len: 6 });
+        assert_eq!(find_cycle(&5, step, 100).unwrap(), Cycle { start: 0, len: 6 });
+        assert_eq!(find_cycle(&0u32, |i: &mut u32| *i = 7, 100).unwrap(), Cycle { start: 1, len: 1 });
+        assert!(find_cycle(&0, step, 9).is_err());
 
-    #[test]
-    fn cycle_4_7() {
-        let actual = find_in_cycle(TEST, 7);
-        let expected = Ok(Cycle { start: (0, 'a'), end: (4, 'e'), target_equiv: (3, 'd'), target: 7 });
-        assert_eq!(actual, expected);
+        assert_eq!([0, 3, 4, 9, 10, 16].map(|i| cycle.equivalent(i)), [0, 3, 4, 9, 4, 4]);
+        assert_eq!([3, 9, 10, 16].map(|i| cycle.complete_cycles(i)), [0, 0, 1, 2]);
     }
 
     #[test]
-    fn cycle_4_8() {
-        let actual = find_in_cycle(TEST, 8);
-        let expected = Ok(Cycle { start: (0, 'a'), end: (4, 'e'), target_equiv: (0, 'a'), target: 8 });
-        assert_eq!(actual, expected);
+    fn nth_states() {
+        for n in 0..40 {
+            let mut expected = 0;
+            (0..n).for_each(|_| { step(&mut expected); });
+            assert_eq!(nth_state(0, step, n), expected, "n = {n}");
+        }
+        // 1_000_000_001 - 4 is 1 more than a multiple of 6
+        assert_eq!(nth_state(0, step, 1_000_000_001), 5);
     }
 
     #[test]
-    fn offset_cycle_4_6() {
-        let actual = find_in_cycle(OFFSET_TEST, 6);
-        let expected = Ok(Cycle { start: (2, 'a'), end: (6, 'e'), target_equiv: (2, 'a'), target: 6 });
-        assert_eq!(actual, expected);
-    }
+    fn frames_in_order() {
+        let framed_step = |i: &mut u32| {
+            crate::frame!("{i}");
+            step(i)
+        };
+        for n in [3, 12, 1000] {
+            let (_, frames) = frames::record(100, || nth_state(0, framed_step, n));
+            let (_, again) = frames::record(100, || extrapolate(0, |i: &mut u32| framed_step(i) as usize, n));
+            assert_eq!(frames, again);
 
-    #[test]
-    fn offset_cycle_4_7() {
-        let actual = find_in_cycle(OFFSET_TEST, 7);
-        let expected = Ok(Cycle { start: (2, 'a'), end: (6, 'e'), target_equiv: (3, 'b'), target: 7 });
-        assert_eq!(actual, expected);
+            let mut expected = 0;
+            for frame in &frames {
+                assert_eq!(frame.text, expected.to_string(), "n = {n}");
+                step(&mut expected);
+            }
+            assert!(frames.len() <= n);
+        }
     }
 
     #[test]
-    fn offset_cycle_4_8() {
-        let actual = find_in_cycle(OFFSET_TEST, 8);
-        let expected = Ok(Cycle { start: (2, 'a'), end: (6, 'e'), target_equiv: (4, 'c'), target: 8 });
-        assert_eq!(actual, expected);
+    fn extrapolation() {
+        for n in 0..40 {
+            let mut expected = (0, 0);
+            (0..n).for_each(|_| expected.1 += step(&mut expected.0) as usize);
+            assert_eq!(extrapolate(0, |i: &mut u32| step(i) as usize, n), expected, "n = {n}");
+        }
+
+        // 0 + 1 + 2 + 3, then 39 for each lap of 4..=9
+        let (state, total) = extrapolate(0, |i: &mut u32| step(i) as usize, 6 * 1_000_000 + 4);
+        assert_eq!((state, total), (4, 6 + 39 * 1_000_000));
     }
 
     #[test]
-    fn offset_cycle_4_9() {
-        let actual = find_in_cycle(OFFSET_TEST, 9);
-        let expected = Ok(Cycle { start: (2, 'a'), end: (6, 'e'), target_equiv: (5, 'd'), target: 9 });
-        assert_eq!(actual, expected);
+    fn accumulation() {
+        let steps = || {
+            let mut state = 0;
+            std::iter::repeat_with(move || {
+                let before = state;
+                (before, step(&mut state) as usize)
+            })
+        };
+
+        for n in 0..40 {
+            let expected = extrapolate(0, |i: &mut u32| step(i) as usize, n).1;
+            assert_eq!(accumulate(steps(), n).unwrap(), expected, "n = {n}");
+        }
+        assert_eq!(accumulate(steps(), 6 * 1_000_000 + 4).unwrap(), 6 + 39 * 1_000_000);
+
+        // Without a cycle, the steps must reach `n`
+        let counting = (0..5).map(|i| (i, 1));
+        assert_eq!(accumulate(counting.clone(), 5).unwrap(), 5);
+        assert!(accumulate(counting, 6).is_err());
     }
 }
@@ -0,0 +1,82 @@
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+
+/// A diagnostic message from solution code, such as a detected cycle or progress.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    /// Module that emitted the event
+    pub source: &'static str,
+    pub message: String,
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+thread_local! {
+    static CAPTURED: RefCell<Option<Vec<Event>>> = const { RefCell::new(None) };
+}
+
+/// Reports `event`: collected if inside [`capture`], otherwise printed to stderr.
+pub fn emit(event: Event) {
+    let event = CAPTURED.with_borrow_mut(|captured| match captured {
+        Some(events) => {
+            events.push(event);
+            None
+        }
+        None => Some(event),
+    });
+
+    if let Some(event) = event {
+        eprintln!("{event}");
+    }
+}
+
+// Restores the enclosing capture, even if `f` panics
+struct Restore(Option<Vec<Event>>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        CAPTURED.set(self.0.take());
+    }
+}
+
+/// Runs `f`, returning its result along with the events it emitted on this thread.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Vec<Event>) {
+    let restore = Restore(CAPTURED.replace(Some(Vec::new())));
+    let result = f();
+    let events = CAPTURED.take().unwrap_or_default();
+    drop(restore);
+    (result, events)
+}
+
+/// Emits a diagnostic [`Event`] with `format!` arguments
+#[macro_export]
+macro_rules! diag {
+    ($($arg:tt)*) => {
+        $crate::diag::emit($crate::diag::Event { source: module_path!(), message: format!($($arg)*) })
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capture_events() {
+        let (answer, events) = capture(|| {
+            diag!("Cycle at {}", 3);
+            let ((), inner) = capture(|| diag!("Inner"));
+            assert_eq!(inner.len(), 1);
+            diag!("After");
+            42
+        });
+
+        assert_eq!(answer, 42);
+        let messages = events.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(messages, ["Cycle at 3", "After"]);
+        assert_eq!(events[0].source, "aoc::diag::tests");
+    }
+}
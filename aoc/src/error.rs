@@ -2,11 +2,77 @@ use std::convert::Infallible;
 use std::fmt::{Debug, Display, Formatter};
 use std::io;
 use std::num::{ParseFloatError, ParseIntError};
+use std::ops::Range;
 use std::str::Utf8Error;
 
+/// Where in the input a parse error happened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// Name of the input, such as its file path
+    pub source: Option<String>,
+    /// 1-based line number
+    pub line: usize,
+    /// Byte range of the offending text within `excerpt`
+    pub span: Range<usize>,
+    /// The line of input
+    pub excerpt: String,
+}
+
+impl Location {
+    pub fn new(line: usize, excerpt: &str, span: Range<usize>) -> Self {
+        Self { source: None, line, span, excerpt: excerpt.to_owned() }
+    }
+
+    /// Location of `part` within `excerpt`, or of the whole excerpt if it isn't there
+    pub fn of(line: usize, excerpt: &str, part: &str) -> Self {
+        let span = match substr_offset(excerpt, part).or_else(|| excerpt.find(part).filter(|_| !part.is_empty())) {
+            Some(start) => start..start + part.len(),
+            None => 0..excerpt.len(),
+        };
+        Self::new(line, excerpt, span)
+    }
+
+    /// 1-based column of the start of the span, in characters
+    pub fn column(&self) -> usize {
+        self.excerpt.get(..self.span.start).map_or(0, |s| s.chars().count()) + 1
+    }
+
+    fn span_chars(&self) -> usize {
+        self.excerpt.get(self.span.clone()).map_or(0, |s| s.chars().count()).max(1)
+    }
+}
+
+/// Byte offset of `part` if it is a slice of `s`
+pub(crate) fn substr_offset(s: &str, part: &str) -> Option<usize> {
+    let start = (part.as_ptr() as usize).checked_sub(s.as_ptr() as usize)?;
+    (start + part.len() <= s.len()).then_some(start)
+}
+
+impl Display for Location {
+    /// Renders as `source:line:column`, then the excerpt with the span underlined
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let line_no = self.line.to_string();
+        let gutter = " ".repeat(line_no.len());
+        writeln!(f, "{gutter}--> {}:{}:{}", self.source.as_deref().unwrap_or("<input>"), self.line, self.column())?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line_no} | {}", self.excerpt)?;
+        write!(f, "{gutter} | {}{}", " ".repeat(self.column() - 1), "^".repeat(self.span_chars()))
+    }
+}
+
 #[derive(Debug)]
 pub struct ParseDataError {
-    pub reason: String
+    pub reason: String,
+    pub location: Option<Location>,
+}
+
+impl Display for ParseDataError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{}\n{location}", self.reason),
+            None => f.write_str(&self.reason),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -18,11 +84,87 @@ pub enum Error {
     Utf8Error(Utf8Error),
     // When not unexpected
     EndOfFile,
+    /// Wrong number of items for a fixed-size collection
+    ItemCount { expected: usize, actual: usize },
+    /// The input was understood but the puzzle can't be solved for it
+    NoSolution(String),
+}
+
+impl Error {
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            Self::ParseDataError(e) => e.location.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Attaches `location` to a parse error that doesn't have one yet
+    pub fn at(self, location: Location) -> Self {
+        let reason = match self {
+            Self::ParseDataError(ParseDataError { reason, location: None }) => reason,
+            Self::ParseIntError(_) | Self::ParseFloatError(_) | Self::Utf8Error(_) | Self::ItemCount { .. } => self.to_string(),
+            _ => return self,
+        };
+
+        Self::ParseDataError(ParseDataError { reason, location: Some(location) })
+    }
+
+    /// Places an error from parsing `line` at 1-based `line_no`.
+    /// A span found within part of the line is kept, otherwise the text named by
+    /// the error is underlined if it appears in the line, or else the whole line.
+    /// An error located in text that isn't part of `line` is returned unchanged.
+    pub fn at_line(mut self, line_no: usize, line: &str) -> Self {
+        if let Self::ParseDataError(ParseDataError { location: Some(location), .. }) = &mut self {
+            let mut matches = line.match_indices(location.excerpt.as_str()).map(|(i, _)| i);
+            match (matches.next(), matches.next()) {
+                (None, _) => return self,
+                // Only widened to the whole line where the part's place in it is certain
+                (Some(offset), None) if location.excerpt != line => {
+                    location.span = location.span.start + offset..location.span.end + offset;
+                    location.excerpt = line.to_owned();
+                }
+                _ => (),
+            }
+            location.line = line_no;
+            return self;
+        }
+
+        let location = match &self {
+            Self::ParseDataError(e) => Location::of(line_no, line, &e.reason),
+            _ => Location::new(line_no, line, 0..line.len()),
+        };
+        self.at(location)
+    }
+
+    /// Moves a located error down `lines` lines, for input parsed a section at a time
+    pub fn after_lines(mut self, lines: usize) -> Self {
+        if let Self::ParseDataError(ParseDataError { location: Some(location), .. }) = &mut self {
+            location.line += lines;
+        }
+        self
+    }
+
+    /// Names the input that a located error came from
+    pub fn in_source(mut self, source: &str) -> Self {
+        if let Self::ParseDataError(ParseDataError { location: Some(location), .. }) = &mut self {
+            location.source.get_or_insert_with(|| source.to_owned());
+        }
+        self
+    }
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        <Self as Debug>::fmt(self, f)
+        match self {
+            Self::IoError(e) => write!(f, "{e}"),
+            Self::ParseDataError(e) => write!(f, "{e}"),
+            Self::ParseIntError(e) => write!(f, "Bad integer: {e}"),
+            Self::ParseFloatError(e) => write!(f, "Bad number: {e}"),
+            Self::Utf8Error(e) => write!(f, "{e}"),
+            Self::EndOfFile => write!(f, "Unexpected end of file"),
+            Self::ItemCount { expected, actual } => write!(f, "Expected {expected} items, found {actual}"),
+            Self::NoSolution(reason) => write!(f, "No solution: {reason}"),
+        }
     }
 }
 
@@ -54,7 +196,7 @@ impl From<ParseDataError> for Error {
 
 impl From<String> for Error {
     fn from(value: String) -> Self {
-        Self::from(ParseDataError { reason: value })
+        Self::from(ParseDataError { reason: value, location: None })
     }
 }
 
@@ -72,7 +214,7 @@ impl From<Utf8Error> for Error {
 
 impl From<u8> for Error {
     fn from(value: u8) -> Self {
-        Self::ParseDataError(ParseDataError { reason: format!("{value}") })
+        Self::ParseDataError(ParseDataError { reason: format!("{value}"), location: None })
     }
 }
 
@@ -85,10 +227,96 @@ impl From<Infallible> for Error {
 #[cfg(feature = "nom")]
 impl From<nom::error::Error<String>> for Error {
     fn from(value: nom::error::Error<String>) -> Self {
-        Self::ParseDataError(ParseDataError { reason: value.to_string() })
+        Self::ParseDataError(ParseDataError { reason: value.to_string(), location: None })
+    }
+}
+
+#[cfg(feature = "gif")]
+impl From<gif::EncodingError> for Error {
+    fn from(value: gif::EncodingError) -> Self {
+        match value {
+            gif::EncodingError::Io(e) => Self::IoError(e),
+            e => Self::IoError(io::Error::other(e)),
+        }
+    }
+}
+
+#[cfg(feature = "png")]
+impl From<png::EncodingError> for Error {
+    fn from(value: png::EncodingError) -> Self {
+        match value {
+            png::EncodingError::IoError(e) => Self::IoError(e),
+            e => Self::IoError(io::Error::other(e)),
+        }
     }
 }
 
 pub fn aoc_err<E>(value: E) -> Error where Error: From<E> {
     Error::from(value)
 }
+
+/// Error for input that parsed but has no answer, such as a maze without a way out
+pub fn no_solution(reason: impl Into<String>) -> Error {
+    Error::NoSolution(reason.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{parse_lines, parse_spaced_vec, AtLine};
+
+    #[test]
+    fn render_location() {
+        let error = parse_spaced_vec::<u64>("50 9x 2").at_line(12, "seeds: 50 9x 2").unwrap_err();
+        let location = error.location().unwrap();
+        assert_eq!((location.line, location.column(), location.span.clone()), (12, 11, 10..12));
+
+        let error = error.in_source("data/day-05.txt");
+        assert_eq!(error.to_string(), concat!(
+            "Bad integer: invalid digit found in string\n",
+            "  --> data/day-05.txt:12:11\n",
+            "   |\n",
+            "12 | seeds: 50 9x 2\n",
+            "   |           ^^",
+        ));
+    }
+
+    #[test]
+    fn locate_reason_in_line() {
+        let error = Err::<(), _>("{x=1,q=2}").at_line(3, "{x=1,q=2}").unwrap_err();
+        assert_eq!(error.location().unwrap().span, 0..9);
+
+        // Parts that appear more than once in the line stay as they are
+        let error = Error::from("Bad").at(Location::new(1, "1,x", 2..3)).at_line(7, "1,x~1,x");
+        assert_eq!(error.location(), Some(&Location::new(7, "1,x", 2..3)));
+        let error = Error::from("Bad").at(Location::new(1, "1,x", 2..3)).at_line(7, "1,x~2,y");
+        assert_eq!(error.location(), Some(&Location::new(7, "1,x~2,y", 2..3)));
+        let error = Error::from("Bad").at(Location::new(1, "z", 0..1)).at_line(7, "1,x");
+        assert_eq!(error.location(), Some(&Location::new(1, "z", 0..1)));
+
+        let error = parse_lines::<u8, _>(["1", "2", "300"].into_iter()).unwrap_err();
+        assert_eq!(error.location().unwrap().line, 3);
+        assert_eq!(error.after_lines(2).location().unwrap().line, 5);
+    }
+
+    #[test]
+    fn unlocated_errors() {
+        let error = aoc_err("No start nodes");
+        assert_eq!(error.to_string(), "No start nodes");
+        assert!(error.location().is_none());
+
+        let error = Error::EndOfFile.at_line(1, "");
+        assert!(error.location().is_none());
+    }
+
+    #[test]
+    fn item_counts() {
+        use crate::CollectArray;
+
+        let error = CollectArray::<3>::try_collect_array("1 2".split(' ')).unwrap_err();
+        assert_eq!(error.to_string(), "Expected 3 items, found 2");
+        let error = CollectArray::<3>::try_collect_array("1 2 3 4 5".split(' ')).unwrap_err();
+        assert_eq!(error.to_string(), "Expected 3 items, found 5");
+        assert_eq!(error.at_line(4, "1 2 3 4 5").location().unwrap().line, 4);
+    }
+}
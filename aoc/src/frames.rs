@@ -0,0 +1,321 @@
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::error::{aoc_err, Error};
+use crate::grid::{Grid, Rgb};
+
+/// Row-major RGB pixels, one per grid cell
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Each pixel blown up to a `scale` pixel square
+    pub fn scaled(&self, scale: usize) -> Image {
+        let mut pixels = Vec::with_capacity(self.pixels.len() * scale * scale);
+        for row in self.pixels.chunks_exact(self.width * 3) {
+            let line = row.chunks_exact(3).flat_map(|rgb| rgb.repeat(scale)).collect::<Vec<_>>();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        Image { width: self.width * scale, height: self.height * scale, pixels }
+    }
+}
+
+/// A snapshot of a simulation's state, emitted by solution code with [`frame!`](crate::frame)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// Module that emitted the frame
+    pub source: &'static str,
+    pub text: String,
+    /// Present for grid snapshots, so the frames can be animated
+    pub image: Option<Image>,
+}
+
+impl Frame {
+    pub fn text(source: &'static str, text: impl Display) -> Self {
+        Self { source, text: text.to_string(), image: None }
+    }
+
+    /// The grid as displayed, and as an image coloured by `colour`
+    pub fn grid<T>(source: &'static str, grid: &Grid<T>, colour: impl FnMut(&T) -> Rgb) -> Self {
+        Self { source, text: grid.to_string(), image: Some(crate::grid::pixels(grid, 1, colour)) }
+    }
+}
+
+struct Recording {
+    frames: Vec<Frame>,
+    limit: usize,
+}
+
+thread_local! {
+    static RECORDING: RefCell<Option<Recording>> = const { RefCell::new(None) };
+}
+
+/// Whether a frame emitted now would be kept, so that emitters can skip building it
+pub fn recording() -> bool {
+    RECORDING.with_borrow(|recording| recording.as_ref().is_some_and(|r| r.frames.len() < r.limit))
+}
+
+/// Keeps `frame` if inside [`record`] and under its limit, otherwise drops it.
+pub fn emit(frame: Frame) {
+    RECORDING.with_borrow_mut(|recording| {
+        if let Some(r) = recording.as_mut().filter(|r| r.frames.len() < r.limit) {
+            r.frames.push(frame);
+        }
+    });
+}
+
+// Restores the enclosing recording, even if `f` panics
+struct Restore(Option<Recording>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        RECORDING.set(self.0.take());
+    }
+}
+
+/// Runs `f`, returning its result along with the first `limit` frames it emitted on this thread.
+pub fn record<T>(limit: usize, f: impl FnOnce() -> T) -> (T, Vec<Frame>) {
+    let restore = Restore(RECORDING.replace(Some(Recording { frames: Vec::new(), limit })));
+    let result = f();
+    let frames = RECORDING.take().map(|r| r.frames).unwrap_or_default();
+    drop(restore);
+    (result, frames)
+}
+
+/// Runs `f` without keeping any frames it emits, such as when replaying steps that were
+/// recorded the first time round
+pub fn unrecorded<T>(f: impl FnOnce() -> T) -> T {
+    let _restore = Restore(RECORDING.take());
+    f()
+}
+
+/// Emits a [`Frame`] if recording: either `grid = <grid>, <colour fn>`, or text with `format!` arguments.
+#[macro_export]
+macro_rules! frame {
+    (grid = $grid:expr, $colour:expr) => {
+        if $crate::frames::recording() {
+            $crate::frames::emit($crate::frames::Frame::grid(module_path!(), &$grid, $colour))
+        }
+    };
+    ($($arg:tt)*) => {
+        if $crate::frames::recording() {
+            $crate::frames::emit($crate::frames::Frame::text(module_path!(), format!($($arg)*)))
+        }
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FramesFormat {
+    /// Each frame's text under a numbered header
+    Text,
+    /// Needs the `gif` feature
+    Gif,
+    /// Animated PNG; needs the `png` feature
+    Apng,
+}
+
+impl FramesFormat {
+    pub fn from_path(path: &Path) -> Result<Self, Error> {
+        let ext = path.extension().and_then(|ext| ext.to_str()).unwrap_or_default();
+        match ext.to_ascii_lowercase().as_str() {
+            "txt" => Ok(Self::Text),
+            "gif" => Ok(Self::Gif),
+            "png" | "apng" => Ok(Self::Apng),
+            _ => Err(aoc_err(format!("Unknown frames format for {}", path.display()))),
+        }
+    }
+}
+
+pub fn write_text<W: Write>(mut out: W, frames: &[Frame]) -> Result<(), Error> {
+    for (i, frame) in frames.iter().enumerate() {
+        writeln!(out, "--- Frame {i} ({}) ---", frame.source)?;
+        writeln!(out, "{}", frame.text.trim_end())?;
+    }
+    Ok(())
+}
+
+/// The frames' images, scaled, checking they all have one
+fn images(frames: &[Frame], scale: usize) -> Result<Vec<Image>, Error> {
+    let images = frames.iter().enumerate()
+        .map(|(i, frame)| match &frame.image {
+            Some(image) => Ok(image.scaled(scale)),
+            None => Err(aoc_err(format!("Frame {i} from {} is text only", frame.source))),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match images.first() {
+        None => Err(aoc_err("No frames to animate")),
+        Some(first) => match images.iter().position(|image| (image.width, image.height) != (first.width, first.height)) {
+            Some(i) => Err(aoc_err(format!("Frame {i} is not the same size as frame 0"))),
+            None => Ok(images),
+        },
+    }
+}
+
+#[cfg(feature = "gif")]
+fn write_gif<W: Write>(out: W, images: &[Image], delay: Duration) -> Result<(), Error> {
+    let size = |n: usize| u16::try_from(n).map_err(|_| aoc_err(format!("Frames too large for GIF: {n} pixels")));
+    let (width, height) = (size(images[0].width)?, size(images[0].height)?);
+    // GIF delays are in hundredths of a second
+    let delay = u16::try_from(delay.as_millis() / 10).unwrap_or(u16::MAX);
+
+    let mut encoder = gif::Encoder::new(out, width, height, &[])?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+    for image in images {
+        let mut frame = gif::Frame::from_rgb_speed(width, height, &image.pixels, 10);
+        frame.delay = delay;
+        encoder.write_frame(&frame)?;
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "gif"))]
+fn write_gif<W: Write>(_out: W, _images: &[Image], _delay: Duration) -> Result<(), Error> {
+    Err(aoc_err("GIF frames need the aoc `gif` feature"))
+}
+
+#[cfg(feature = "png")]
+fn write_apng<W: Write>(out: W, images: &[Image], delay: Duration) -> Result<(), Error> {
+    let size = |n: usize| u32::try_from(n).map_err(|_| aoc_err(format!("Frames too large for PNG: {n} pixels")));
+    let count = size(images.len())?;
+    let mut encoder = png::Encoder::new(out, size(images[0].width)?, size(images[0].height)?);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_animated(count, 0)?;
+    encoder.set_frame_delay(u16::try_from(delay.as_millis()).unwrap_or(u16::MAX), 1000)?;
+
+    let mut writer = encoder.write_header()?;
+    for image in images {
+        writer.write_image_data(&image.pixels)?;
+    }
+    writer.finish()?;
+    Ok(())
+}
+
+#[cfg(not(feature = "png"))]
+fn write_apng<W: Write>(_out: W, _images: &[Image], _delay: Duration) -> Result<(), Error> {
+    Err(aoc_err("APNG frames need the aoc `png` feature"))
+}
+
+/// Writes `frames` in `format`, animations having `scale` pixels to a cell and `delay` between frames
+pub fn write_frames<W: Write>(out: W, frames: &[Frame], format: FramesFormat, scale: usize, delay: Duration)
+    -> Result<(), Error>
+{
+    match format {
+        FramesFormat::Text => write_text(out, frames),
+        FramesFormat::Gif => write_gif(out, &images(frames, scale)?, delay),
+        FramesFormat::Apng => write_apng(out, &images(frames, scale)?, delay),
+    }
+}
+
+/// As [`write_frames`], in the format given by the file extension. Nothing is written
+/// unless all the frames can be.
+pub fn save_frames(path: &Path, frames: &[Frame], scale: usize, delay: Duration) -> Result<(), Error> {
+    let format = FramesFormat::from_path(path)?;
+    let mut out = Vec::new();
+    write_frames(&mut out, frames, format, scale, delay)?;
+    fs::write(path, out)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use crate::grid::read_grid_ascii;
+
+    fn colour(&cell: &u8) -> Rgb {
+        if cell == b'#' { Rgb::BLACK } else { Rgb::WHITE }
+    }
+
+    fn grid_frames() -> Vec<Frame> {
+        let mut grid = read_grid_ascii(&mut Cursor::new("#.\n..\n"), None).unwrap();
+        record(10, || {
+            for pos in [(0, 1), (1, 1)] {
+                grid[pos] = b'#';
+                crate::frame!(grid = grid, colour);
+            }
+        }).1
+    }
+
+    #[test]
+    fn record_frames() {
+        let ((), frames) = record(2, || {
+            crate::frame!("Step {}", 1);
+            assert!(recording());
+            crate::frame!("Step {}", 2);
+            assert!(!recording());
+            crate::frame!("Step {}", 3);
+        });
+        let texts = frames.iter().map(|f| f.text.as_str()).collect::<Vec<_>>();
+        assert_eq!(texts, ["Step 1", "Step 2"]);
+
+        let ((), frames) = record(5, || {
+            crate::frame!("Before");
+            unrecorded(|| crate::frame!("Hidden"));
+            crate::frame!("After");
+        });
+        assert_eq!(frames.iter().map(|f| f.text.as_str()).collect::<Vec<_>>(), ["Before", "After"]);
+        assert_eq!(frames[0].source, "aoc::frames::tests");
+        assert!(!recording());
+
+        let frames = grid_frames();
+        assert_eq!(frames[1].text, "##\n.#\n");
+        assert_eq!(frames[1].image.as_ref().unwrap().pixels[6..], [255, 255, 255, 0, 0, 0]);
+    }
+
+    #[test]
+    fn text_frames() {
+        let mut out = Vec::new();
+        write_frames(&mut out, &grid_frames(), FramesFormat::Text, 1, Duration::ZERO).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text, "--- Frame 0 (aoc::frames::tests) ---\n##\n..\n--- Frame 1 (aoc::frames::tests) ---\n##\n.#\n");
+    }
+
+    #[test]
+    fn scaled_images() {
+        let image = Image { width: 2, height: 1, pixels: vec![1, 1, 1, 2, 2, 2] };
+        let scaled = image.scaled(2);
+        assert_eq!((scaled.width, scaled.height), (4, 2));
+        assert_eq!(scaled.pixels, [[1; 6], [2; 6], [1; 6], [2; 6]].concat());
+    }
+
+    #[test]
+    fn unanimatable() {
+        let frames = [Frame::text("test", "abc")];
+        assert!(write_frames(Vec::new(), &frames, FramesFormat::Gif, 1, Duration::ZERO).is_err());
+        assert!(write_frames(Vec::new(), &[], FramesFormat::Apng, 1, Duration::ZERO).is_err());
+
+        // Leaving no empty file behind
+        let path = std::env::temp_dir().join(format!("aoc-frames-test-{}.gif", std::process::id()));
+        assert!(save_frames(&path, &frames, 1, Duration::ZERO).is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    #[cfg(feature = "gif")]
+    fn gif() {
+        let mut out = Vec::new();
+        write_frames(&mut out, &grid_frames(), FramesFormat::Gif, 2, Duration::from_millis(100)).unwrap();
+        assert!(out.starts_with(b"GIF89a"));
+    }
+
+    #[test]
+    #[cfg(feature = "png")]
+    fn apng() {
+        let mut out = Vec::new();
+        write_frames(&mut out, &grid_frames(), FramesFormat::Apng, 2, Duration::from_millis(100)).unwrap();
+        assert!(out.starts_with(b"\x89PNG"));
+        assert!(out.windows(4).any(|w| w == b"acTL"));
+    }
+}
@@ -5,7 +5,7 @@ use std::borrow::Borrow;
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::io::BufRead;
-use std::iter::repeat;
+use std::iter::repeat_n;
 use std::ops::{Index, IndexMut};
 use std::rc::Rc;
 use std::str::FromStr;
@@ -13,8 +13,22 @@ use std::str::FromStr;
 use enumset::{EnumSet, EnumSetType};
 use itertools::Itertools;
 
+use crate::error::aoc_err;
 use crate::infallible;
 
+mod compass;
+mod export;
+mod sparse;
+mod view;
+mod wrapping;
+
+pub use compass::{Compass, CompassMap, Compasses};
+pub use export::{ImageFormat, Rgb};
+pub(crate) use export::pixels;
+pub use sparse::{Coords, SparseGrid};
+pub use view::GridView;
+pub use wrapping::Wrapping;
+
 #[derive(Debug, EnumSetType)]
 pub enum Axis {
     Row = 0, Column = 1
@@ -150,6 +164,72 @@ impl<T> Grid<T> {
         self.shape = new_shape;
     }
 
+    /// Mirrors left to right
+    pub fn flip_horizontal(&mut self) {
+        if !self.is_empty() {
+            self.cells.chunks_exact_mut(self.shape.1).for_each(<[T]>::reverse);
+        }
+    }
+
+    /// Mirrors top to bottom
+    pub fn flip_vertical(&mut self) {
+        self.rotate_180();
+        self.flip_horizontal();
+    }
+
+    /// Rotates a quarter turn clockwise
+    pub fn rotate_cw(&mut self) {
+        self.transpose();
+        self.flip_horizontal();
+    }
+
+    /// Rotates a quarter turn counter-clockwise
+    pub fn rotate_ccw(&mut self) {
+        self.transpose();
+        self.flip_vertical();
+    }
+
+    pub fn rotate_180(&mut self) {
+        self.cells.reverse();
+    }
+
+    pub fn flipped_horizontal(&self) -> Self where T: Clone {
+        self.transformed(Self::flip_horizontal)
+    }
+
+    pub fn flipped_vertical(&self) -> Self where T: Clone {
+        self.transformed(Self::flip_vertical)
+    }
+
+    pub fn rotated_cw(&self) -> Self where T: Clone {
+        self.transformed(Self::rotate_cw)
+    }
+
+    pub fn rotated_ccw(&self) -> Self where T: Clone {
+        self.transformed(Self::rotate_ccw)
+    }
+
+    pub fn rotated_180(&self) -> Self where T: Clone {
+        self.transformed(Self::rotate_180)
+    }
+
+    fn transformed(&self, transform: impl FnOnce(&mut Self)) -> Self where T: Clone {
+        let mut grid = self.clone();
+        transform(&mut grid);
+        grid
+    }
+
+    /// Zero-copy view of the whole grid
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView::new(self, (0, 0), self.shape)
+    }
+
+    /// Zero-copy view of the `shape` rectangle with top left at `origin`,
+    /// if it lies within the grid
+    pub fn view(&self, origin: (usize, usize), shape: (usize, usize)) -> Option<GridView<'_, T>> {
+        self.as_view().view(origin, shape)
+    }
+
     pub fn position<F>(&self, mut predicate: F) -> Option<(usize, usize)>
         where F: FnMut(&T) -> bool
     {
@@ -164,6 +244,11 @@ impl<T> Grid<T> {
         Some(self.to_2d(i))
     }
 
+    /// View of this grid tiled endlessly in every direction, unless it is empty
+    pub const fn wrapping(&self) -> Option<Wrapping<'_, T>> {
+        Wrapping::new(self)
+    }
+
     pub fn step<I: num::PrimInt>(&self, pos: (I, I), way: Way) -> Option<(I, I)> {
         self.steps(pos, way, I::one())
     }
@@ -180,6 +265,16 @@ impl<T> Grid<T> {
             _ => Some(way.steps((r, c), count))
         }
     }
+
+    /// Neighbouring cells up, right, down and left that lie within the grid
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item=(usize, usize)> + '_ {
+        Way::all().iter().filter_map(move |way| self.step(pos, way))
+    }
+
+    /// Neighbouring cells in all eight directions, clockwise from up, that lie within the grid
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item=(usize, usize)> + '_ {
+        Compass::all().iter().filter_map(move |dir| dir.steps_within(pos, 1, self.shape))
+    }
 }
 
 impl<T: PartialEq> PartialEq for Grid<T> {
@@ -366,12 +461,12 @@ pub fn read_grid_with_transform<R, T, E, F, D>(
             expected_col_count = col_count;
             if let Some(padding) = padding_value.clone() {
                 // First row all padding
-                cells.extend(repeat(padding).take(col_count));
+                cells.extend(repeat_n(padding, col_count));
             }
         }
 
         if col_count != expected_col_count {
-            return Err(format!("Ragged line at line {}", r + 1).into());
+            return Err(aoc_err::<&str>("Ragged line").at_line(r + 1, &line));
         }
 
         if let Some(padding) = padding_value.clone() {
@@ -401,7 +496,12 @@ pub fn read_grid_with_transform<R, T, E, F, D>(
 
 #[cfg(test)]
 mod tests {
-    use super::Grid;
+    use super::{read_grid_ascii, Grid};
+    use std::io::Cursor;
+
+    fn grid(s: &str) -> Grid<u8> {
+        read_grid_ascii(&mut Cursor::new(s), None).unwrap()
+    }
 
     #[test]
     fn transpose_row() {
@@ -489,6 +589,26 @@ mod tests {
         g.transpose();
         assert_eq!(g, expected);
     }
+
+    #[test]
+    fn rotations() {
+        let mut g = grid("abc\ndef\n");
+        assert_eq!(g.rotated_cw(), grid("da\neb\nfc\n"));
+        assert_eq!(g.rotated_ccw(), grid("cf\nbe\nad\n"));
+        assert_eq!(g.rotated_180(), grid("fed\ncba\n"));
+        assert_eq!(g.flipped_horizontal(), grid("cba\nfed\n"));
+        assert_eq!(g.flipped_vertical(), grid("def\nabc\n"));
+
+        let original = g.clone();
+        g.rotate_cw();
+        g.rotate_cw();
+        g.rotate_ccw();
+        g.rotate_ccw();
+        assert_eq!(g, original);
+        g.flip_vertical();
+        g.flip_horizontal();
+        assert_eq!(g, original.rotated_180());
+    }
 }
 
 pub type Ways = EnumSet<Way>;
@@ -0,0 +1,257 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use enumset::{EnumSet, EnumSetType};
+
+use crate::grid::{Way, Ways};
+
+pub type Compasses = EnumSet<Compass>;
+
+/// The eight directions to a neighbouring cell, clockwise from up
+#[derive(Debug, Hash, PartialOrd, Ord, EnumSetType)]
+pub enum Compass {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Compass {
+    const CLOCKWISE: [Compass; 8] = [
+        Compass::Up, Compass::UpRight, Compass::Right, Compass::DownRight,
+        Compass::Down, Compass::DownLeft, Compass::Left, Compass::UpLeft,
+    ];
+
+    pub fn all() -> Compasses {
+        Compasses::all()
+    }
+
+    pub fn cardinals() -> Compasses {
+        Compass::Up | Compass::Right | Compass::Down | Compass::Left
+    }
+
+    pub fn diagonals() -> Compasses {
+        Compass::UpRight | Compass::DownRight | Compass::DownLeft | Compass::UpLeft
+    }
+
+    pub const fn is_diagonal(&self) -> bool {
+        (*self as usize) % 2 == 1
+    }
+
+    const fn eighths_cw(&self, n: usize) -> Self {
+        Self::CLOCKWISE[(*self as usize + n) % 8]
+    }
+
+    pub const fn flipped(&self) -> Self {
+        self.eighths_cw(4)
+    }
+
+    /// An eighth turn clockwise
+    pub const fn rotate_cw(&self) -> Self {
+        self.eighths_cw(1)
+    }
+
+    /// An eighth turn counter-clockwise
+    pub const fn rotate_ccw(&self) -> Self {
+        self.eighths_cw(7)
+    }
+
+    /// The cardinal ways this direction combines
+    pub fn ways(&self) -> Ways {
+        match self {
+            Compass::Up => Way::Up.into(),
+            Compass::UpRight => Way::Up | Way::Right,
+            Compass::Right => Way::Right.into(),
+            Compass::DownRight => Way::Down | Way::Right,
+            Compass::Down => Way::Down.into(),
+            Compass::DownLeft => Way::Down | Way::Left,
+            Compass::Left => Way::Left.into(),
+            Compass::UpLeft => Way::Up | Way::Left,
+        }
+    }
+
+    pub fn step<I: num::PrimInt>(&self, pos: (I, I)) -> (I, I) {
+        self.steps(pos, I::one())
+    }
+
+    pub fn steps<I: num::PrimInt>(&self, pos: (I, I), count: I) -> (I, I) {
+        self.ways().iter().fold(pos, |pos, way| way.steps(pos, count))
+    }
+
+    /// As [`steps`](Self::steps), unless that would leave a grid of `shape`
+    pub fn steps_within(&self, (r, c): (usize, usize), count: usize, (rows, cols): (usize, usize)) -> Option<(usize, usize)> {
+        self.ways().iter().try_fold((r, c), |(r, c), way| match way {
+            Way::Up => Some((r.checked_sub(count)?, c)),
+            Way::Right => Some((r, c.checked_add(count).filter(|&c| c < cols)?)),
+            Way::Down => Some((r.checked_add(count).filter(|&r| r < rows)?, c)),
+            Way::Left => Some((r, c.checked_sub(count)?)),
+        })
+    }
+}
+
+impl From<Way> for Compass {
+    fn from(value: Way) -> Self {
+        match value {
+            Way::Up => Compass::Up,
+            Way::Right => Compass::Right,
+            Way::Down => Compass::Down,
+            Way::Left => Compass::Left,
+        }
+    }
+}
+
+impl TryFrom<Compass> for Way {
+    type Error = Compass;
+
+    fn try_from(value: Compass) -> Result<Way, Compass> {
+        match value {
+            Compass::Up => Ok(Way::Up),
+            Compass::Right => Ok(Way::Right),
+            Compass::Down => Ok(Way::Down),
+            Compass::Left => Ok(Way::Left),
+            _ => Err(value),
+        }
+    }
+}
+
+impl From<Compass> for char {
+    fn from(value: Compass) -> Self {
+        match value {
+            Compass::Up => '↑',
+            Compass::UpRight => '↗',
+            Compass::Right => '→',
+            Compass::DownRight => '↘',
+            Compass::Down => '↓',
+            Compass::DownLeft => '↙',
+            Compass::Left => '←',
+            Compass::UpLeft => '↖',
+        }
+    }
+}
+
+impl Display for Compass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CompassMap<V>([Option<V>; 8]);
+
+impl<V> CompassMap<V> {
+    pub fn new() -> Self {
+        Self([const { None }; 8])
+    }
+
+    pub fn with_all_default() -> Self where V: Default {
+        Self(std::array::from_fn(|_| Some(V::default())))
+    }
+
+    pub fn with_all(v: V) -> Self where V: Clone {
+        Self(std::array::from_fn(|_| Some(v.clone())))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.iter().flatten().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn insert(&mut self, k: Compass, v: V) -> bool {
+        let bucket = &mut self.0[k as usize];
+        let result = bucket.is_none();
+        *bucket = Some(v);
+        result
+    }
+
+    pub fn get(&self, k: &Compass) -> Option<&V> {
+        self.0[*k as usize].as_ref()
+    }
+
+    pub fn get_mut(&mut self, k: &Compass) -> Option<&mut V> {
+        self.0[*k as usize].as_mut()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=(Compass, &V)> {
+        Compass::CLOCKWISE.into_iter()
+            .filter_map(|k|
+                self.0[k as usize].as_ref().map(|v| (k, v))
+            )
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item=Compass> + '_ {
+        self.iter().map(|(k, _)| k)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item=&V> + '_ {
+        self.iter().map(|(_, v)| v)
+    }
+}
+
+impl<V> Index<Compass> for CompassMap<V> {
+    type Output = V;
+
+    fn index(&self, index: Compass) -> &Self::Output {
+        self.get(&index).unwrap_or_else(|| panic!("No such key"))
+    }
+}
+
+impl<V> IndexMut<Compass> for CompassMap<V> {
+    fn index_mut(&mut self, index: Compass) -> &mut Self::Output {
+        self.get_mut(&index).unwrap_or_else(|| panic!("No such key"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    #[test]
+    fn turns() {
+        assert_eq!(Compass::UpLeft.rotate_cw(), Compass::Up);
+        assert_eq!(Compass::Up.rotate_ccw(), Compass::UpLeft);
+        assert_eq!(Compass::DownRight.flipped(), Compass::UpLeft);
+        assert!(Compass::all().iter().all(|dir| dir.flipped().flipped() == dir));
+        assert_eq!(Compass::cardinals().iter().map(Way::try_from).filter(Result::is_ok).count(), 4);
+        assert_eq!(Compass::diagonals().iter().filter(Compass::is_diagonal).count(), 4);
+        assert_eq!(Compass::from(Way::Left).to_string(), "←");
+    }
+
+    #[test]
+    fn steps() {
+        assert_eq!(Compass::UpRight.step((5isize, 5)), (4, 6));
+        assert_eq!(Compass::DownLeft.steps((5isize, 5), 2), (7, 3));
+        assert_eq!(Compass::UpLeft.steps_within((1, 1), 1, (2, 2)), Some((0, 0)));
+        assert_eq!(Compass::UpLeft.steps_within((0, 1), 1, (2, 2)), None);
+        assert_eq!(Compass::DownRight.steps_within((0, 1), 1, (2, 2)), None);
+    }
+
+    #[test]
+    fn compass_map() {
+        let mut map = CompassMap::new();
+        assert!(map.insert(Compass::DownLeft, 3));
+        assert!(!map.insert(Compass::DownLeft, 4));
+        map.insert(Compass::Up, 1);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[Compass::DownLeft], 4);
+        assert_eq!(map.keys().collect::<Vec<_>>(), [Compass::Up, Compass::DownLeft]);
+        assert_eq!(CompassMap::with_all(0).len(), 8);
+    }
+
+    #[test]
+    fn grid_neighbours() {
+        let grid: Grid<u8> = Grid::new_ascii((3, 4));
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), [(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((0, 0)).collect::<Vec<_>>(), [(0, 1), (1, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((2, 3)).collect::<Vec<_>>(), [(1, 3), (2, 2), (1, 2)]);
+    }
+}
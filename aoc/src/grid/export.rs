@@ -0,0 +1,170 @@
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use itertools::Itertools;
+
+use crate::error::{aoc_err, Error};
+use crate::frames::Image;
+use crate::grid::Grid;
+
+/// 24-bit colour for a cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0, 0, 0);
+    pub const WHITE: Self = Self(255, 255, 255);
+
+    /// `#rrggbb`
+    pub fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Binary portable pixmap, readable without any extra dependencies
+    Ppm,
+    /// Needs the `png` feature
+    Png,
+    /// One rectangle per run of same-coloured cells, so it stays sharp at any zoom
+    Svg,
+}
+
+impl ImageFormat {
+    pub fn from_path(path: &Path) -> Result<Self, Error> {
+        let ext = path.extension().and_then(|ext| ext.to_str()).unwrap_or_default();
+        match ext.to_ascii_lowercase().as_str() {
+            "ppm" => Ok(Self::Ppm),
+            "png" => Ok(Self::Png),
+            "svg" => Ok(Self::Svg),
+            _ => Err(aoc_err(format!("Unknown image format for {}", path.display()))),
+        }
+    }
+}
+
+/// `grid` as an image, with `scale` pixels to a cell side
+pub(crate) fn pixels<T>(grid: &Grid<T>, scale: usize, colour: impl FnMut(&T) -> Rgb) -> Image {
+    let (height, width) = grid.shape();
+    let pixels = grid.iter_rows().flatten().map(colour).flat_map(|Rgb(r, g, b)| [r, g, b]).collect();
+    let image = Image { width, height, pixels };
+    if scale == 1 { image } else { image.scaled(scale) }
+}
+
+fn write_ppm<W: Write>(mut out: W, image: Image) -> Result<(), Error> {
+    write!(out, "P6\n{} {}\n255\n", image.width, image.height)?;
+    out.write_all(&image.pixels)?;
+    Ok(())
+}
+
+#[cfg(feature = "png")]
+fn write_png<W: Write>(out: W, Image { width, height, pixels }: Image) -> Result<(), Error> {
+    let size = |n: usize| u32::try_from(n).map_err(|_| aoc_err(format!("Image too large: {width}x{height}")));
+    let mut encoder = png::Encoder::new(out, size(width)?, size(height)?);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(&pixels)?;
+    Ok(())
+}
+
+fn write_svg<T, W: Write>(grid: &Grid<T>, mut out: W, scale: usize, mut colour: impl FnMut(&T) -> Rgb) -> Result<(), Error> {
+    let (rows, cols) = grid.shape();
+    writeln!(out, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {cols} {rows}" shape-rendering="crispEdges">"#,
+             cols * scale, rows * scale)?;
+    for (r, row) in grid.iter_rows().enumerate() {
+        let runs = row.iter().map(&mut colour).dedup_with_count();
+        let mut c = 0;
+        for (len, fill) in runs {
+            writeln!(out, r#"<rect x="{c}" y="{r}" width="{len}" height="1" fill="{}"/>"#, fill.hex())?;
+            c += len;
+        }
+    }
+    writeln!(out, "</svg>")?;
+    Ok(())
+}
+
+impl<T> Grid<T> {
+    /// Draws the grid as an image, each cell a `scale` pixel square coloured by `colour`
+    pub fn write_image<W: Write>(&self, out: W, format: ImageFormat, scale: usize, colour: impl FnMut(&T) -> Rgb)
+        -> Result<(), Error>
+    {
+        match format {
+            ImageFormat::Ppm => write_ppm(out, pixels(self, scale, colour)),
+            #[cfg(feature = "png")]
+            ImageFormat::Png => write_png(out, pixels(self, scale, colour)),
+            #[cfg(not(feature = "png"))]
+            ImageFormat::Png => Err(aoc_err("PNG export needs the aoc `png` feature")),
+            ImageFormat::Svg => write_svg(self, out, scale, colour),
+        }
+    }
+
+    /// As [`write_image`](Self::write_image), in the format given by the file extension.
+    /// Nothing is written unless the whole image can be.
+    pub fn save_image(&self, path: &Path, scale: usize, colour: impl FnMut(&T) -> Rgb) -> Result<(), Error> {
+        let format = ImageFormat::from_path(path)?;
+        let mut out = Vec::new();
+        self.write_image(&mut out, format, scale, colour)?;
+        fs::write(path, out)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use crate::grid::read_grid_ascii;
+
+    fn colour(&cell: &u8) -> Rgb {
+        if cell == b'#' { Rgb::BLACK } else { Rgb::WHITE }
+    }
+
+    fn image(format: ImageFormat, scale: usize) -> Vec<u8> {
+        let grid = read_grid_ascii(&mut Cursor::new("#..\n.##\n"), None).unwrap();
+        let mut out = Vec::new();
+        grid.write_image(&mut out, format, scale, colour).unwrap();
+        out
+    }
+
+    #[test]
+    fn ppm() {
+        let ppm = image(ImageFormat::Ppm, 2);
+        let header = b"P6\n6 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 6 * 4 * 3);
+        // Second pixel of the first row is still the top left cell
+        assert_eq!(&ppm[header.len()..header.len() + 9], [0, 0, 0, 0, 0, 0, 255, 255, 255]);
+    }
+
+    #[test]
+    fn svg() {
+        let svg = String::from_utf8(image(ImageFormat::Svg, 10)).unwrap();
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="30" height="20" viewBox="0 0 3 2""#));
+        assert!(svg.contains(r##"<rect x="1" y="0" width="2" height="1" fill="#ffffff"/>"##));
+        assert!(svg.contains(r##"<rect x="1" y="1" width="2" height="1" fill="#000000"/>"##));
+        assert_eq!(svg.matches("<rect").count(), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "png")]
+    fn png() {
+        assert!(image(ImageFormat::Png, 1).starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    #[cfg(not(feature = "png"))]
+    fn png_unsupported() {
+        let grid = read_grid_ascii(&mut Cursor::new("#.
+"), None).unwrap();
+        let path = std::env::temp_dir().join(format!("aoc-export-test-{}.png", std::process::id()));
+        assert!(grid.save_image(&path, 1, colour).is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(ImageFormat::from_path(Path::new("day-21.PNG")).unwrap(), ImageFormat::Png);
+        assert!(ImageFormat::from_path(Path::new("day-21")).is_err());
+    }
+}
@@ -0,0 +1,269 @@
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use fxhash::FxHashMap;
+
+use crate::grid::{Grid, Way};
+
+/// Signed (row, column), which may lie anywhere
+pub type Coords = (isize, isize);
+
+pub type CellDisplay<T> = fn(&T, &mut Formatter) -> fmt::Result;
+
+/// An unbounded grid holding only the cells that have been set, every other cell
+/// reading as the default value.
+#[derive(Clone)]
+pub struct SparseGrid<T> {
+    cells: FxHashMap<Coords, T>,
+    default: T,
+    // Inclusive (min, max) corners of the set cells
+    bounds: Option<(Coords, Coords)>,
+    display: CellDisplay<T>,
+}
+
+impl SparseGrid<u8> {
+    pub fn new_ascii() -> Self {
+        Self::with_display(b'.', ascii_fmt)
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self
+        where T: Display
+    {
+        Self::with_display(default, display_fmt)
+    }
+
+    /// Empty grid rendering each cell with `display`
+    pub fn with_display(default: T, display: CellDisplay<T>) -> Self {
+        Self { cells: FxHashMap::default(), default, bounds: None, display }
+    }
+
+    pub const fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Number of cells set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Coords) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: Coords) -> &T {
+        self.cells.get(&pos).unwrap_or(&self.default)
+    }
+
+    pub fn insert(&mut self, pos: Coords, value: T) -> Option<T> {
+        self.include(pos);
+        self.cells.insert(pos, value)
+    }
+
+    /// Returns the cell to the default value, giving back what was set
+    pub fn remove(&mut self, pos: Coords) -> Option<T> {
+        let value = self.cells.remove(&pos)?;
+        if let Some(((min_r, min_c), (max_r, max_c))) = self.bounds {
+            let (r, c) = pos;
+            if r == min_r || r == max_r || c == min_c || c == max_c {
+                self.bounds = None;
+                for &pos in self.cells.keys() {
+                    Self::extend_bounds(&mut self.bounds, pos);
+                }
+            }
+        }
+        Some(value)
+    }
+
+    /// The set cells, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item=(Coords, &T)> + '_ {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    /// Inclusive (min, max) corners of the region holding every set cell
+    pub const fn bounds(&self) -> Option<(Coords, Coords)> {
+        self.bounds
+    }
+
+    /// (rows, columns) of the bounds
+    pub fn shape(&self) -> (usize, usize) {
+        self.bounds.map_or((0, 0), |((min_r, min_c), (max_r, max_c))|
+            (max_r.abs_diff(min_r) + 1, max_c.abs_diff(min_c) + 1)
+        )
+    }
+
+    /// Sets every cell of `grid` that isn't the default, with `grid[(0, 0)]` at `(r0, c0)`
+    pub fn extend_from_grid(&mut self, grid: &Grid<T>, (r0, c0): Coords)
+        where T: Clone + PartialEq
+    {
+        for (r, row) in grid.iter_rows().enumerate() {
+            for (c, value) in row.iter().enumerate() {
+                if *value != self.default {
+                    self.insert((r0 + r as isize, c0 + c as isize), value.clone());
+                }
+            }
+        }
+    }
+
+    /// Never leaves the grid, so only fails on overflow
+    pub fn step<I: num::PrimInt>(&self, pos: (I, I), way: Way) -> Option<(I, I)> {
+        self.steps(pos, way, I::one())
+    }
+
+    pub fn steps<I: num::PrimInt>(&self, (r, c): (I, I), way: Way, count: I) -> Option<(I, I)> {
+        match way {
+            Way::Up => Some((r.checked_sub(&count)?, c)),
+            Way::Right => Some((r, c.checked_add(&count)?)),
+            Way::Down => Some((r.checked_add(&count)?, c)),
+            Way::Left => Some((r, c.checked_sub(&count)?)),
+        }
+    }
+
+    fn include(&mut self, pos: Coords) {
+        Self::extend_bounds(&mut self.bounds, pos);
+    }
+
+    fn extend_bounds(bounds: &mut Option<(Coords, Coords)>, (r, c): Coords) {
+        *bounds = Some(match *bounds {
+            None => ((r, c), (r, c)),
+            Some(((min_r, min_c), (max_r, max_c))) => ((min_r.min(r), min_c.min(c)), (max_r.max(r), max_c.max(c))),
+        });
+    }
+}
+
+impl<T> Index<Coords> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Coords) -> &Self::Output {
+        self.get(pos)
+    }
+}
+
+/// Sets the cell to a copy of the default value first if needed
+impl<T: Clone> IndexMut<Coords> for SparseGrid<T> {
+    fn index_mut(&mut self, pos: Coords) -> &mut Self::Output {
+        self.include(pos);
+        self.cells.entry(pos).or_insert_with(|| self.default.clone())
+    }
+}
+
+impl<T> Extend<(Coords, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item=(Coords, T)>>(&mut self, iter: I) {
+        for (pos, value) in iter {
+            self.insert(pos, value);
+        }
+    }
+}
+
+/// Equal when every cell reads the same, however the cells came to be set
+impl<T: PartialEq> PartialEq for SparseGrid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        let reads_as = |a: &Self, b: &Self| a.cells.iter().all(|(&pos, value)| value == b.get(pos));
+        self.default == other.default && reads_as(self, other) && reads_as(other, self)
+    }
+}
+
+impl<T: Eq> Eq for SparseGrid<T> {}
+
+impl<T> Display for SparseGrid<T> {
+    /// Renders the bounds, unset cells showing the default value
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Some(((min_r, min_c), (max_r, max_c))) = self.bounds else {
+            return Ok(());
+        };
+
+        for r in min_r..=max_r {
+            for c in min_c..=max_c {
+                (self.display)(self.get((r, c)), f)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Debug for SparseGrid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)?;
+        writeln!(f, "bounds: {:?}", self.bounds)
+    }
+}
+
+fn ascii_fmt(cell: &u8, f: &mut Formatter) -> fmt::Result {
+    write!(f, "{}", *cell as char)
+}
+
+fn display_fmt<T: Display>(cell: &T, f: &mut Formatter) -> fmt::Result {
+    if f.alternate() {
+        write!(f, "{:#}", cell)
+    } else {
+        write!(f, "{}", cell)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use crate::grid::read_grid_ascii;
+
+    #[test]
+    fn default_and_bounds() {
+        let mut g = SparseGrid::new(0);
+        assert_eq!(g.bounds(), None);
+        assert_eq!(g.shape(), (0, 0));
+
+        g.insert((-2, 5), 7);
+        g[(3, -1)] += 2;
+        assert_eq!(g[(-2, 5)], 7);
+        assert_eq!(g[(3, -1)], 2);
+        assert_eq!(g[(100, 100)], 0);
+        assert_eq!(g.len(), 2);
+        assert_eq!(g.bounds(), Some(((-2, -1), (3, 5))));
+        assert_eq!(g.shape(), (6, 7));
+
+        assert_eq!(g.remove((3, -1)), Some(2));
+        assert_eq!(g.remove((3, -1)), None);
+        assert_eq!(g.bounds(), Some(((-2, 5), (-2, 5))));
+    }
+
+    #[test]
+    fn steps() {
+        let g = SparseGrid::new_ascii();
+        assert_eq!(g.step((0isize, 0), Way::Up), Some((-1, 0)));
+        assert_eq!(g.steps((0isize, 0), Way::Left, 3), Some((0, -3)));
+        assert_eq!(g.step((0usize, 0), Way::Left), None);
+        assert_eq!(g.step((isize::MAX, 0), Way::Down), None);
+    }
+
+    #[test]
+    fn equality() {
+        let mut g = SparseGrid::new(0);
+        g.insert((1, 1), 5);
+        let mut h = g.clone();
+        let _ = &mut h[(2, 2)];
+        h.insert((3, 3), 0);
+        assert_eq!(g, h);
+        assert_eq!(h, g);
+
+        h.insert((1, 1), 6);
+        assert_ne!(g, h);
+        assert_ne!(g, SparseGrid::new(1));
+    }
+
+    #[test]
+    fn display() {
+        let grid = read_grid_ascii(&mut Cursor::new("#..\n.#.\n"), None).unwrap();
+        let mut g = SparseGrid::new_ascii();
+        g.extend_from_grid(&grid, (-1, -1));
+        g.insert((1, 2), b'O');
+        assert_eq!(g.len(), 3);
+        assert_eq!(g.to_string(), "#...\n.#..\n...O\n");
+    }
+}
@@ -0,0 +1,163 @@
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+use std::ops::Index;
+
+use crate::grid::{Axis, Grid};
+
+/// A borrowed rectangle of a [`Grid`], indexed from its own top left corner
+pub struct GridView<'g, T> {
+    grid: &'g Grid<T>,
+    origin: (usize, usize),
+    shape: (usize, usize),
+}
+
+impl<'g, T> GridView<'g, T> {
+    pub(super) const fn new(grid: &'g Grid<T>, origin: (usize, usize), shape: (usize, usize)) -> Self {
+        Self { grid, origin, shape }
+    }
+
+    pub const fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    /// Top left corner within the underlying grid
+    pub const fn origin(&self) -> (usize, usize) {
+        self.origin
+    }
+
+    pub const fn len(&self, axis: Axis) -> usize {
+        match axis {
+            Axis::Row => self.shape.0,
+            Axis::Column => self.shape.1,
+        }
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.shape.0 == 0 || self.shape.1 == 0
+    }
+
+    /// The `shape` rectangle with top left at `origin` within this view, if it fits
+    pub fn view(&self, (r, c): (usize, usize), shape: (usize, usize)) -> Option<Self> {
+        let fits = r.checked_add(shape.0).is_some_and(|end| end <= self.shape.0)
+            && c.checked_add(shape.1).is_some_and(|end| end <= self.shape.1);
+        fits.then(|| Self::new(self.grid, (self.origin.0 + r, self.origin.1 + c), shape))
+    }
+
+    /// The view's part of the grid's row `r`
+    pub fn row(&self, r: usize) -> &'g [T] {
+        let (r0, c0) = self.origin;
+        &self.grid[r0 + r][c0..c0 + self.shape.1]
+    }
+
+    pub fn column(&self, c: usize) -> impl DoubleEndedIterator<Item=&'g T> + ExactSizeIterator + 'g {
+        let view = *self;
+        (0..self.shape.0).map(move |r| &view.row(r)[c])
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item=&'g [T]> + ExactSizeIterator + 'g {
+        let view = *self;
+        (0..self.shape.0).map(move |r| view.row(r))
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item=impl DoubleEndedIterator<Item=&'g T> + ExactSizeIterator + 'g> + ExactSizeIterator + 'g {
+        let view = *self;
+        (0..self.shape.1).map(move |c| view.column(c))
+    }
+
+    /// Row or column `i`, as [`Grid::get`]
+    pub fn get(&self, axis: Axis, i: usize) -> impl DoubleEndedIterator<Item=&'g T> + ExactSizeIterator + 'g {
+        let view = *self;
+        (0..self.len(axis.other())).map(move |j| match axis {
+            Axis::Row => &view.row(i)[j],
+            Axis::Column => &view.row(j)[i],
+        })
+    }
+
+    /// Copies the view into a grid of its own
+    pub fn to_grid(&self) -> Grid<T> where T: Clone {
+        let cells = self.rows().flat_map(|row| row.iter().cloned()).collect();
+        Grid { shape: self.shape, cells, display: self.grid.display.clone() }
+    }
+}
+
+// Not derived, which would require `T: Copy`
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<T> Index<(usize, usize)> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (r, c): (usize, usize)) -> &Self::Output {
+        &self.row(r)[c]
+    }
+}
+
+/// Views are equal if their cells are, wherever they lie
+impl<T: PartialEq> PartialEq for GridView<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.shape == other.shape && self.rows().eq(other.rows())
+    }
+}
+
+impl<T: Eq> Eq for GridView<'_, T> {}
+
+impl<T: PartialEq> PartialEq<Grid<T>> for GridView<'_, T> {
+    fn eq(&self, other: &Grid<T>) -> bool {
+        *self == other.as_view()
+    }
+}
+
+impl<T> Debug for GridView<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GridView")
+            .field("origin", &self.origin)
+            .field("shape", &self.shape)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use crate::grid::read_grid_ascii;
+
+    fn grid(s: &str) -> Grid<u8> {
+        read_grid_ascii(&mut Cursor::new(s), None).unwrap()
+    }
+
+    #[test]
+    fn views() {
+        let g = grid("abcd\nefgh\nijkl\n");
+        let v = g.view((1, 1), (2, 2)).unwrap();
+        assert_eq!(v.shape(), (2, 2));
+        assert_eq!(v[(0, 0)], b'f');
+        assert_eq!(v.row(1), b"jk");
+        assert_eq!(v.column(1).copied().collect::<Vec<_>>(), b"gk");
+        assert_eq!(v.columns().map(|c| c.count()).collect::<Vec<_>>(), [2, 2]);
+        assert_eq!(v.get(Axis::Column, 0).rev().copied().collect::<Vec<_>>(), b"jf");
+        assert_eq!(v, grid("fg\njk\n"));
+        assert_eq!(v.to_grid(), grid("fg\njk\n"));
+
+        let inner = v.view((1, 0), (1, 2)).unwrap();
+        assert_eq!(inner.origin(), (2, 1));
+        assert_eq!(inner.row(0), b"jk");
+
+        assert!(g.view((2, 0), (2, 1)).is_none());
+        assert!(g.view((0, 3), (1, 2)).is_none());
+        assert!(g.view((3, 4), (0, 0)).is_some_and(|v| v.is_empty()));
+    }
+
+    #[test]
+    fn equal_regions() {
+        let g = grid("abab\ncdcd\n");
+        assert_eq!(g.view((0, 0), (2, 2)), g.view((0, 2), (2, 2)));
+        assert_ne!(g.view((0, 0), (2, 2)), g.view((0, 1), (2, 2)));
+        assert_ne!(g.view((0, 0), (1, 2)), g.view((0, 0), (2, 1)));
+    }
+}
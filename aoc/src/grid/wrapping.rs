@@ -0,0 +1,100 @@
+use std::ops::Index;
+
+use crate::grid::{Coords, Grid, Way};
+
+/// A [`Grid`] repeated endlessly in every direction, indexed by signed coordinates.
+/// The copy at tile `(0, 0)` is the grid itself, which is never empty.
+#[derive(Debug)]
+pub struct Wrapping<'g, T> {
+    grid: &'g Grid<T>,
+}
+
+impl<'g, T> Wrapping<'g, T> {
+    /// `None` for an empty grid, which has nothing to repeat
+    pub const fn new(grid: &'g Grid<T>) -> Option<Self> {
+        match grid.shape() {
+            (0, _) | (_, 0) => None,
+            _ => Some(Self { grid }),
+        }
+    }
+
+    pub const fn grid(&self) -> &'g Grid<T> {
+        self.grid
+    }
+
+    fn signed_shape(&self) -> Coords {
+        let (rows, cols) = self.grid.shape();
+        (rows as isize, cols as isize)
+    }
+
+    /// Position within the grid of the cell at `pos`
+    pub fn wrap(&self, (r, c): Coords) -> (usize, usize) {
+        let (rows, cols) = self.signed_shape();
+        (r.rem_euclid(rows) as usize, c.rem_euclid(cols) as usize)
+    }
+
+    /// Which copy of the grid `pos` lies in
+    pub fn tile(&self, (r, c): Coords) -> Coords {
+        let (rows, cols) = self.signed_shape();
+        (r.div_euclid(rows), c.div_euclid(cols))
+    }
+
+    /// Inverse of [`wrap`](Self::wrap) and [`tile`](Self::tile)
+    pub fn unwrap(&self, (r, c): (usize, usize), (tile_r, tile_c): Coords) -> Coords {
+        let (rows, cols) = self.signed_shape();
+        (tile_r * rows + r as isize, tile_c * cols + c as isize)
+    }
+
+    pub fn get(&self, pos: Coords) -> &'g T {
+        &self.grid[self.wrap(pos)]
+    }
+
+    pub fn step(&self, pos: Coords, way: Way) -> Coords {
+        way.step(pos)
+    }
+
+    pub fn steps(&self, pos: Coords, way: Way, count: isize) -> Coords {
+        way.steps(pos, count)
+    }
+}
+
+// Not derived, which would require `T: Copy`
+impl<T> Clone for Wrapping<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Wrapping<'_, T> {}
+
+impl<T> Index<Coords> for Wrapping<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: Coords) -> &Self::Output {
+        self.get(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use crate::grid::read_grid_ascii;
+
+    #[test]
+    fn wrapped_positions() {
+        let grid = read_grid_ascii(&mut Cursor::new("abc\ndef\n"), None).unwrap();
+        let w = grid.wrapping().unwrap();
+        assert_eq!(w[(0, 0)], b'a');
+        assert_eq!(w[(-1, -1)], b'f');
+        assert_eq!(w[(4, 7)], b'b');
+        assert_eq!(w.wrap((-3, 5)), (1, 2));
+        assert_eq!(w.tile((-3, 5)), (-2, 1));
+        assert_eq!(w.tile((1, 2)), (0, 0));
+        assert_eq!(w.unwrap((1, 2), (-2, 1)), (-3, 5));
+        assert_eq!(w.steps((0, 0), Way::Left, 4), (0, -4));
+        assert_eq!(w[w.step((0, 0), Way::Up)], b'd');
+
+        assert!(Grid::<u8>::new_ascii((0, 3)).wrapping().is_none());
+    }
+}
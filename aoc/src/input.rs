@@ -0,0 +1,171 @@
+use std::convert::Infallible;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::data_dir;
+use crate::error::{aoc_err, Error};
+use crate::solution::Puzzle;
+
+/// Input file (or `-` for stdin) to use instead of `data/day-NN.txt`
+pub const INPUT_VAR: &str = "AOC_INPUT";
+/// Directory holding `day-NN.txt` inputs, instead of searching for `data`
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Builtin(&'static str),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Name for error locations
+    pub fn name(&self) -> String {
+        match self {
+            Self::Builtin(_) => "<built-in>".to_owned(),
+            Self::File(path) => path.display().to_string(),
+            Self::Stdin => "<stdin>".to_owned(),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, Error> {
+        match self {
+            Self::Builtin(input) => Ok((*input).to_owned()),
+            Self::File(path) => fs::read_to_string(path)
+                .map_err(|e| aoc_err(format!("Can't read {}: {e}", path.display()))),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    /// A file path, or `-` for stdin
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Self::Stdin,
+            _ => Self::File(s.into()),
+        })
+    }
+}
+
+/// Where to find puzzle inputs. In order of preference: an explicit source,
+/// then the puzzle's built-in input, then `day-NN.txt` in the data directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputConfig {
+    pub source: Option<InputSource>,
+    pub data_dir: Option<PathBuf>,
+}
+
+impl InputConfig {
+    /// Configuration from `$AOC_INPUT` and `$AOC_DATA_DIR`, if set
+    pub fn from_env() -> Self {
+        Self {
+            source: env::var(INPUT_VAR).ok().map(|s| s.parse().unwrap_or_else(|e| match e {})),
+            data_dir: env::var_os(DATA_DIR_VAR).map(PathBuf::from),
+        }
+    }
+
+    pub fn data_dir(&self) -> Result<PathBuf, Error> {
+        match &self.data_dir {
+            Some(dir) => Ok(dir.clone()),
+            None => data_dir(),
+        }
+    }
+
+    /// `day-NN.txt` in the data directory, whether or not it exists
+    pub fn default_path(&self, puzzle: &dyn Puzzle) -> Result<PathBuf, Error> {
+        Ok(self.data_dir()?.join(format!("{}.txt", puzzle.name())))
+    }
+
+    pub fn resolve(&self, puzzle: &dyn Puzzle) -> Result<InputSource, Error> {
+        if let Some(source) = &self.source {
+            return Ok(source.clone());
+        }
+
+        if let Some(input) = puzzle.builtin_input() {
+            return Ok(InputSource::Builtin(input));
+        }
+
+        let path = self.default_path(puzzle)?;
+        if !path.is_file() {
+            return Err(aoc_err(format!("No input for {}: {} not found", puzzle.name(), path.display())));
+        }
+
+        Ok(InputSource::File(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::any::Any;
+    use std::path::Path;
+
+    struct Day(Option<&'static str>);
+
+    impl Puzzle for Day {
+        fn day(&self) -> u8 {
+            7
+        }
+
+        fn builtin_input(&self) -> Option<&'static str> {
+            self.0
+        }
+
+        fn parts(&self) -> u8 {
+            0
+        }
+
+        fn parse(&self, _input: &str) -> Result<Box<dyn Any>, Error> {
+            Err(aoc_err("Not parsed in these tests"))
+        }
+
+        fn part1(&self, _input: &dyn Any) -> Result<String, Error> {
+            Ok(String::new())
+        }
+
+        fn part2(&self, _input: &dyn Any) -> Result<String, Error> {
+            Ok(String::new())
+        }
+    }
+
+    fn config(dir: &Path) -> InputConfig {
+        InputConfig { source: None, data_dir: Some(dir.to_owned()) }
+    }
+
+    #[test]
+    fn parse_source() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!("in/day-01.txt".parse(), Ok(InputSource::File("in/day-01.txt".into())));
+    }
+
+    #[test]
+    fn resolve_order() {
+        let dir = env::temp_dir().join(format!("aoc-input-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("day-07.txt");
+
+        assert!(config(&dir).resolve(&Day(None)).is_err());
+
+        fs::write(&path, "32T3K 765").unwrap();
+        let source = config(&dir).resolve(&Day(None)).unwrap();
+        assert_eq!(source, InputSource::File(path.clone()));
+        assert_eq!(source.read().unwrap(), "32T3K 765");
+
+        assert_eq!(config(&dir).resolve(&Day(Some("builtin"))).unwrap(), InputSource::Builtin("builtin"));
+
+        let explicit = InputConfig { source: Some(InputSource::Stdin), ..config(&dir) };
+        assert_eq!(explicit.resolve(&Day(Some("builtin"))).unwrap(), InputSource::Stdin);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
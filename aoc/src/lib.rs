@@ -1,48 +1,63 @@
 #![allow(clippy::redundant_field_names)]
-#![feature(maybe_uninit_uninit_array)]
 #![feature(maybe_uninit_array_assume_init)]
-#![feature(new_uninit)]
 #![feature(step_trait)]
 
-use std::{env, fs};
+use std::env;
 use std::cmp::Ordering;
 use std::convert::Infallible;
 use std::mem::MaybeUninit;
 use std::ops::{Add, AddAssign};
-use std::panic::panic_any;
 use std::path::PathBuf;
 use itertools::Itertools;
 
+use crate::error::{aoc_err, Error};
+use crate::input::DATA_DIR_VAR;
+
 pub mod cycle;
+pub mod diag;
+pub mod frames;
 pub mod grid;
+pub mod input;
 pub mod parse;
 pub mod range;
+pub mod search;
+pub mod bench;
+pub mod solution;
+pub mod verify;
+#[cfg(feature = "tui")]
+pub mod viewer;
 pub mod error;
 
-fn find_dir(dirname: &str) -> PathBuf {
-    let cwd = env::current_dir().expect("Can't get current directory");
-    if cwd.ends_with(dirname) {
-        return cwd;
-    }
+fn find_dir(dirname: &str) -> Result<PathBuf, Error> {
+    let cwd = env::current_dir()?;
+    // Search upwards, so that any directory in the workspace will do
+    for dir in cwd.ancestors() {
+        if dir.ends_with(dirname) {
+            return Ok(dir.to_owned());
+        }
 
-    let dir_list = fs::read_dir(cwd).expect("Can't read current directory");
-    // flatten: skip unreadable directories
-    for d in dir_list.flatten() {
-        if d.file_name() == dirname {
-            return d.path();
+        let candidate = dir.join(dirname);
+        if candidate.is_dir() {
+            return Ok(candidate);
         }
     }
 
-    panic!("Can't find directory {dirname}");
+    Err(aoc_err(format!("Can't find directory {dirname} from {} (set ${DATA_DIR_VAR})", cwd.display())))
+}
+
+/// `$AOC_DATA_DIR` if set, otherwise the nearest `data` directory
+pub fn data_dir() -> Result<PathBuf, Error> {
+    match env::var_os(DATA_DIR_VAR) {
+        Some(dir) => Ok(dir.into()),
+        None => find_dir("data"),
+    }
 }
 
-pub fn find_path(filename: &str) -> PathBuf {
-    let mut day_dir = find_dir("data");
-    day_dir.push(filename);
-    day_dir
+pub fn find_path(filename: &str) -> Result<PathBuf, Error> {
+    Ok(data_dir()?.join(filename))
 }
 
-pub fn find_input_path(day_dirname: &str) -> PathBuf {
+pub fn find_input_path(day_dirname: &str) -> Result<PathBuf, Error> {
     find_path(&format!("{day_dirname}.txt"))
 }
 
@@ -56,29 +71,26 @@ pub fn infallible<F, T, R>(mut f: F) -> impl FnMut(T) -> Result<R, Infallible>
 #[must_use]
 // Copied from #[unstable(feature = "is_sorted", reason = "new API", issue = "53485")]
 pub fn is_sorted<T: PartialOrd>(slice: &[T]) -> bool {
-    slice.iter().tuple_windows::<(_, _)>().all(|(a, b)| a.partial_cmp(b).map_or(false, Ordering::is_le))
+    slice.iter().tuple_windows::<(_, _)>().all(|(a, b)| a.partial_cmp(b).is_some_and(Ordering::is_le))
 }
 
 pub trait CollectArray<const N: usize> where Self: Iterator + Sized {
-    fn try_collect_array(self) -> Result<[Self::Item; N], &'static str>;
-
-    fn collect_array(self) -> [Self::Item; N] {
-        self.try_collect_array().unwrap_or_else(|e| panic_any(e))
-    }
+    /// Collects exactly `N` items, failing with [`Error::ItemCount`] for any other number
+    fn try_collect_array(self) -> Result<[Self::Item; N], Error>;
 }
 
 impl<const N: usize, I: Iterator> CollectArray<N> for I {
-    fn try_collect_array(mut self) -> Result<[Self::Item; N], &'static str> {
-        let mut result: [MaybeUninit<Self::Item>; N] = MaybeUninit::uninit_array();
-        for r in &mut result {
-            let next = self.next().ok_or("Two few items for array")?;
+    fn try_collect_array(mut self) -> Result<[Self::Item; N], Error> {
+        let mut result: [MaybeUninit<Self::Item>; N] = [const { MaybeUninit::uninit() }; N];
+        for (i, r) in result.iter_mut().enumerate() {
+            let next = self.next().ok_or(Error::ItemCount { expected: N, actual: i })?;
             r.write(next);
         }
 
         match self.next() {
             // Safety: All elements have been written by the above loop
             None => Ok(unsafe { MaybeUninit::array_assume_init(result) }),
-            Some(_) => Err("Too many items for array"),
+            Some(_) => Err(Error::ItemCount { expected: N, actual: N + 1 + self.count() }),
         }
     }
 }
@@ -3,22 +3,48 @@ use std::str::FromStr;
 
 use itertools::Itertools;
 
-use crate::error::Error;
+use crate::error::{Error, Location};
 
-pub fn parse_spaced_vec<T>(line: &str) -> Result<Vec<T>, T::Err> where T: FromStr {
+#[cfg(feature = "nom")]
+pub mod combinators;
+mod pattern;
+mod sections;
+
+pub use pattern::Pattern;
+pub use sections::{Section, Sections};
+
+/// Parses whitespace-separated values. Errors point at the offending value, on line 1
+/// unless placed with [`AtLine::at_line`].
+pub fn parse_spaced_vec<T>(line: &str) -> Result<Vec<T>, Error> where T: FromStr, Error: From<T::Err> {
     parse_spaced(line)
 }
 
-pub fn parse_spaced<T, C>(line: &str) -> Result<C, T::Err>
-    where T: FromStr, C: FromIterator<T>
+pub fn parse_spaced<T, C>(line: &str) -> Result<C, Error>
+    where T: FromStr, C: FromIterator<T>, Error: From<T::Err>
 {
-    line.split_ascii_whitespace().map(|n| n.parse::<T>()).try_collect()
+    line.split_ascii_whitespace()
+        .map(|n| n.parse::<T>().map_err(|e| Error::from(e).at(Location::of(1, line, n))))
+        .try_collect()
 }
 
-pub fn parse_lines<T, S>(lines: impl Iterator<Item=S>) -> Result<Vec<T>, T::Err>
-    where T: FromStr, S: Borrow<str>
+/// Parses each line, with errors located at their line number
+pub fn parse_lines<T, S>(lines: impl Iterator<Item=S>) -> Result<Vec<T>, Error>
+    where T: FromStr, S: Borrow<str>, Error: From<T::Err>
 {
-    lines.map(|line| line.borrow().parse()).try_collect()
+    lines.enumerate()
+        .map(|(i, line)| line.borrow().parse().at_line(i + 1, line.borrow()))
+        .try_collect()
+}
+
+pub trait AtLine<T> {
+    /// Locates an error from parsing `line` at 1-based `line_no`
+    fn at_line(self, line_no: usize, line: &str) -> Result<T, Error>;
+}
+
+impl<T, E> AtLine<T> for Result<T, E> where Error: From<E> {
+    fn at_line(self, line_no: usize, line: &str) -> Result<T, Error> {
+        self.map_err(|e| Error::from(e).at_line(line_no, line))
+    }
 }
 
 pub fn some_ok_or<T, E>(item: Option<Result<T, E>>, message: &str) -> Result<T, Error>
@@ -28,34 +54,40 @@ pub fn some_ok_or<T, E>(item: Option<Result<T, E>>, message: &str) -> Result<T,
     result.map_err(Error::from)
 }
 
-pub trait ParseExt<T: ?Sized> where T: FromStr {
-    fn please(&self, s: &str) -> Result<T, String>;
+fn bad_format(s: &str) -> Error {
+    Error::from(format!("Unexpected format: {s}")).at(Location::new(1, s, 0..s.len()))
+}
+
+pub trait ParseExt<T> where T: FromStr {
+    /// Parses `self`, which is part of `s`; errors point at `self` within `s`
+    fn please(&self, s: &str) -> Result<T, Error>;
 }
 
-impl<T: ?Sized> ParseExt<T> for str where T: FromStr {
-    fn please(&self, s: &str) -> Result<T, String> {
-        self.parse::<T>().map_err(|_| s.to_string())
+impl<T> ParseExt<T> for str where T: FromStr {
+    fn please(&self, s: &str) -> Result<T, Error> {
+        self.parse::<T>().map_err(|_| Error::from(format!("Can't parse '{self}'")).at(Location::of(1, s, self)))
     }
 }
 
-impl<T: ?Sized> ParseExt<T> for &str where T: FromStr {
-    fn please(&self, s: &str) -> Result<T, String> {
-        self.parse::<T>().map_err(|_| s.to_string())
+impl<T> ParseExt<T> for &str where T: FromStr {
+    fn please(&self, s: &str) -> Result<T, Error> {
+        (**self).please(s)
     }
 }
 
 pub trait OkOrErr<T> {
-    fn ok_or_err(self, s: &str) -> Result<T, String>;
+    /// Converts a failure to understand `s` into an error pointing at `s`
+    fn ok_or_err(self, s: &str) -> Result<T, Error>;
 }
 
 impl<T> OkOrErr<T> for Option<T> {
-    fn ok_or_err(self, s: &str) -> Result<T, String> {
-        self.ok_or_else(|| s.to_owned())
+    fn ok_or_err(self, s: &str) -> Result<T, Error> {
+        self.ok_or_else(|| bad_format(s))
     }
 }
 
 impl<T, E> OkOrErr<T> for Result<T, E> {
-    fn ok_or_err(self, s: &str) -> Result<T, String> {
-        self.map_err(|_| s.to_owned())
+    fn ok_or_err(self, s: &str) -> Result<T, Error> {
+        self.map_err(|_| bad_format(s))
     }
 }
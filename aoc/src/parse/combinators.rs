@@ -0,0 +1,225 @@
+use std::str::FromStr;
+
+use nom::{Finish, IResult, Parser};
+use nom::bytes::complete::{tag, take_till1, take_while1};
+use nom::character::complete::{char, digit1, line_ending, multispace0, one_of, space0, space1};
+use nom::combinator::{all_consuming, cut, eof, map_res, not, opt, recognize};
+use nom::error::ErrorKind;
+use nom::number::complete::recognize_float;
+use nom::multi::{many1, separated_list0, separated_list1};
+use nom::sequence::{delimited, pair, preceded, separated_pair, terminated};
+
+use crate::error::{Error, Location};
+
+type NomError<'a> = nom::error::Error<&'a str>;
+
+/// Runs `parser` over the whole of `input`, bar trailing whitespace. Errors point at the
+/// line and column where parsing stopped.
+pub fn parse_all<'a, O, F>(input: &'a str, parser: F) -> Result<O, Error>
+    where F: Parser<&'a str, O, NomError<'a>>
+{
+    all_consuming(terminated(parser, multispace0))(input)
+        .finish()
+        .map(|(_, output)| output)
+        .map_err(|e| located(input, e))
+}
+
+// Converts an error at some point within `input` to one located on its line
+fn located(input: &str, e: NomError) -> Error {
+    let offset = input.len() - e.input.len();
+    let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+    let line = input[line_start..line_end].trim_end_matches('\r');
+    let line_no = input[..line_start].matches('\n').count() + 1;
+
+    // The word or symbol where parsing stopped
+    let rest = &line[(offset - line_start).min(line.len())..];
+    let token_len = match rest.find(|c: char| !is_word_char(c)) {
+        Some(0) => rest.chars().next().map_or(0, char::len_utf8),
+        Some(len) => len,
+        None => rest.len(),
+    };
+    let token = &rest[..token_len];
+    let reason = match (token, e.code) {
+        ("", _) => format!("Unexpected end of line ({})", describe(e.code)),
+        (_, ErrorKind::Eof) => format!("Unexpected '{token}'"),
+        _ => format!("Can't parse '{token}' ({})", describe(e.code)),
+    };
+    Error::from(reason).at(Location::of(line_no, line, token))
+}
+
+fn describe(kind: ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::Digit | ErrorKind::Float => "expected a number",
+        ErrorKind::TakeWhile1 | ErrorKind::TakeTill1 => "expected a name",
+        ErrorKind::MapRes => "invalid value",
+        ErrorKind::Char | ErrorKind::Tag => "expected punctuation",
+        _ => "unexpected text",
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// `parser`, with any spaces or tabs either side
+pub fn trim<'a, O, F>(parser: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+    where F: Parser<&'a str, O, NomError<'a>>
+{
+    delimited(space0, parser, space0)
+}
+
+/// An optionally signed integer, parsed as `T`
+pub fn integer<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// A decimal number with an optional exponent, such as `-19.5` or `1e3`, parsed as `T`
+pub fn float<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize_float, str::parse)(input).map_err(|e| e.map(|e: NomError| match e.code {
+        ErrorKind::MapRes => e,
+        _ => NomError::new(e.input, ErrorKind::Float),
+    }))
+}
+
+/// A name made of letters, digits and underscores
+pub fn word(input: &str) -> IResult<&str, &str> {
+    take_while1(is_word_char)(input)
+}
+
+/// Integers separated by spaces, such as `79 14 55 13`
+pub fn spaced<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    separated_list1(space1, integer)(input)
+}
+
+// One or more `item`s separated by `sep`. Unlike `separated_list1`, an item that fails
+// after a separator fails the whole list, so that errors point at the bad item.
+fn strictly_separated<'a, O, P, F, G>(mut sep: G, mut item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+    where
+        F: Parser<&'a str, O, NomError<'a>>,
+        G: Parser<&'a str, P, NomError<'a>>,
+{
+    move |input| {
+        let (mut input, first) = item.parse(input)?;
+        let mut items = vec![first];
+        while let Ok((rest, _)) = sep.parse(input) {
+            let (rest, next) = cut(|i| item.parse(i))(rest)?;
+            items.push(next);
+            input = rest;
+        }
+        Ok((input, items))
+    }
+}
+
+/// Items separated by commas, with or without spaces
+pub fn list<'a, O, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+    where F: Parser<&'a str, O, NomError<'a>>
+{
+    strictly_separated(trim(char(',')), item)
+}
+
+/// `key: values`, giving the key and the values. The key runs up to the first colon.
+pub fn header<'a, O, F>(values: F) -> impl FnMut(&'a str) -> IResult<&'a str, (&'a str, O)>
+    where F: Parser<&'a str, O, NomError<'a>>
+{
+    separated_pair(take_till1(|c| c == ':' || c == '\n'), pair(char(':'), space0), values)
+}
+
+/// `name -> a, b, c`, with `source` parsing the name and `target` each of the list
+pub fn adjacency<'a, S, T, F, G>(source: F, target: G) -> impl FnMut(&'a str) -> IResult<&'a str, (S, Vec<T>)>
+    where
+        F: Parser<&'a str, S, NomError<'a>>,
+        G: Parser<&'a str, T, NomError<'a>>,
+{
+    separated_pair(source, trim(tag("->")), list(target))
+}
+
+/// `{k=v,...}`, giving each key with its value
+pub fn record<'a, V, F>(value: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<(&'a str, V)>>
+    where F: Parser<&'a str, V, NomError<'a>>
+{
+    delimited(char('{'), separated_list0(char(','), separated_pair(word, char('='), value)), char('}'))
+}
+
+/// Exactly `N` comma separated items, such as `19, 13, 30`
+pub fn vector<'a, T, F, const N: usize>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, [T; N]>
+    where F: Parser<&'a str, T, NomError<'a>>
+{
+    map_res(list(item), <[T; N]>::try_from)
+}
+
+/// A position and velocity of `N` items each, such as `19, 13, 30 @ -2, 1, -2`
+pub fn position_velocity<'a, T, F, const N: usize>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, ([T; N], [T; N])>
+    where F: Parser<&'a str, T, NomError<'a>> + Clone
+{
+    separated_pair(vector(item.clone()), trim(char('@')), vector(item))
+}
+
+/// Lines each parsed by `line`
+pub fn lines<'a, O, F>(line: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+    where F: Parser<&'a str, O, NomError<'a>>
+{
+    separated_list1(line_ending, line)
+}
+
+/// Sections separated by blank lines, each parsed by `section`
+pub fn sections<'a, O, F>(section: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+    where F: Parser<&'a str, O, NomError<'a>>
+{
+    // Blank lines at the end don't start another section
+    strictly_separated(terminated(preceded(line_ending, many1(line_ending)), not(eof)), section)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shapes() {
+        assert_eq!(parse_all("79 14  -55", spaced::<i32>).unwrap(), [79, 14, -55]);
+        assert_eq!(parse_all("seeds: 79 14", header(spaced::<u64>)).unwrap(), ("seeds", vec![79, 14]));
+        assert_eq!(parse_all("seed-to-soil map:", header(space0)).unwrap().0, "seed-to-soil map");
+
+        let (name, targets) = parse_all("%a -> b,c , d", adjacency(recognize(pair(opt(char('%')), word)), word)).unwrap();
+        assert_eq!((name, targets), ("%a", vec!["b", "c", "d"]));
+
+        let part = parse_all("{x=787,m=2655}", record(integer::<u32>)).unwrap();
+        assert_eq!(part, [("x", 787), ("m", 2655)]);
+
+        let hailstone = parse_all("19, 13, 30 @ -2,  1, -2", position_velocity(integer::<i64>)).unwrap();
+        assert_eq!(hailstone, ([19, 13, 30], [-2, 1, -2]));
+
+        let floats: [f64; 4] = parse_all("19.5, 1e3, -2, .25", vector(float)).unwrap();
+        assert_eq!(floats, [19.5, 1000.0, -2.0, 0.25]);
+    }
+
+    #[test]
+    fn blocks() {
+        let input = "a b\nc\n\n\nd\n\ne f\n\n";
+        let blocks = parse_all(input, sections(lines(separated_list1(space1, word)))).unwrap();
+        assert_eq!(blocks, [vec![vec!["a", "b"], vec!["c"]], vec![vec!["d"]], vec![vec!["e", "f"]]]);
+    }
+
+    #[test]
+    fn errors() {
+        let e = parse_all("1 2 x", spaced::<u8>).unwrap_err();
+        assert_eq!(e.location(), Some(&Location::new(1, "1 2 x", 4..5)));
+        assert!(e.to_string().starts_with("Unexpected 'x'"));
+
+        // Out of range numbers fail where they start
+        let e = parse_all("1, 300, 2", vector::<_, _, 3>(integer::<u8>)).unwrap_err();
+        assert_eq!(e.location(), Some(&Location::new(1, "1, 300, 2", 3..6)));
+        assert!(e.to_string().starts_with("Can't parse '300' (invalid value)"));
+
+        let e = parse_all("{x=1}\n\n{y=2,z}", sections(record(integer::<u8>))).unwrap_err();
+        let location = e.location().unwrap();
+        assert_eq!((location.line, location.column(), location.excerpt.as_str()), (3, 5, "{y=2,z}"));
+
+        let e = parse_all("1, 2", vector::<_, _, 3>(integer::<u8>)).unwrap_err();
+        assert_eq!(e.location().unwrap().column(), 1);
+
+        let e = parse_all("1.5, x", list(float::<f64>)).unwrap_err();
+        assert_eq!(e.location(), Some(&Location::new(1, "1.5, x", 5..6)));
+        assert!(e.to_string().starts_with("Can't parse 'x' (expected a number)"));
+    }
+}
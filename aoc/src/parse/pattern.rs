@@ -0,0 +1,97 @@
+use std::str::FromStr;
+
+use crate::error::{Error, Location};
+use crate::parse::ParseExt;
+
+/// A string being matched against a format such as `{x},{y}~{z}`, a literal or field at a
+/// time. This is what `#[derive(FromFormat)]` from `aoc-derive` expands to.
+#[derive(Debug, Clone, Copy)]
+pub struct Pattern<'a> {
+    s: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Pattern<'a> {
+    pub fn new(s: &'a str) -> Self {
+        Self { s, rest: s }
+    }
+
+    // From where matching has got to, up to `len` bytes on
+    fn location(&self, len: usize) -> Location {
+        let start = self.s.len() - self.rest.len();
+        Location::new(1, self.s, start..start + len.min(self.rest.len()))
+    }
+
+    /// Matches `literal` next
+    pub fn literal(&mut self, literal: &str) -> Result<(), Error> {
+        match self.rest.strip_prefix(literal) {
+            Some(rest) => {
+                self.rest = rest;
+                Ok(())
+            }
+            None => Err(Error::from(format!("Expected '{literal}'")).at(self.location(literal.len()))),
+        }
+    }
+
+    /// Parses the text up to the next `until`, or to the end if `None`, ignoring
+    /// surrounding whitespace
+    pub fn field<T: FromStr>(&mut self, until: Option<&str>) -> Result<T, Error> {
+        let len = match until {
+            Some(until) => self.rest.find(until)
+                .ok_or_else(|| Error::from(format!("Expected '{until}'")).at(self.location(self.rest.len())))?,
+            None => self.rest.len(),
+        };
+
+        let (text, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        text.trim().please(self.s)
+    }
+
+    /// Checks that all of the string was matched
+    pub fn end(self) -> Result<(), Error> {
+        match self.rest {
+            "" => Ok(()),
+            rest => Err(Error::from(format!("Unexpected '{rest}'")).at(self.location(rest.len()))),
+        }
+    }
+
+    /// Error for a string matching none of `formats`
+    pub fn no_match(s: &str, formats: &[&str]) -> Error {
+        let formats = formats.iter().map(|f| format!("'{f}'")).collect::<Vec<_>>();
+        Error::from(format!("Expected one of {}", formats.join(", "))).at(Location::new(1, s, 0..s.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // As derived for `{x},{y}~{name}`
+    fn parse(s: &str) -> Result<(u32, i8, String), Error> {
+        let mut pattern = Pattern::new(s);
+        let x = pattern.field(Some(","))?;
+        pattern.literal(",")?;
+        let y = pattern.field(Some("~"))?;
+        pattern.literal("~")?;
+        let name = pattern.field(None)?;
+        pattern.end()?;
+        Ok((x, y, name))
+    }
+
+    #[test]
+    fn fields() {
+        assert_eq!(parse("1,-2~abc").unwrap(), (1, -2, "abc".to_owned()));
+        assert_eq!(parse("1 , 2 ~ ab c").unwrap(), (1, 2, "ab c".to_owned()));
+
+        let e = parse("1,300~a").unwrap_err();
+        assert_eq!(e.location(), Some(&Location::new(1, "1,300~a", 2..5)));
+        let e = parse("1,2").unwrap_err();
+        assert_eq!(e.to_string().lines().next(), Some("Expected '~'"));
+        assert_eq!(e.location().unwrap().span, 2..3);
+
+        let mut pattern = Pattern::new("R 4");
+        assert!(pattern.literal("L").is_err());
+        pattern.literal("R").unwrap();
+        assert!(pattern.end().is_err());
+    }
+}
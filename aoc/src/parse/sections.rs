@@ -0,0 +1,165 @@
+use std::io::{BufRead, Lines};
+use std::str::FromStr;
+
+use crate::error::{aoc_err, Error, Location};
+use crate::grid::{read_grid_ascii, Grid};
+use crate::parse::parse_lines;
+
+/// A block of lines from input split at blank lines
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// Position among the sections, from 0
+    pub index: usize,
+    /// Number of input lines before this section
+    pub offset: usize,
+    /// Never empty, and none of them blank
+    pub lines: Vec<String>,
+}
+
+impl Section {
+    /// Input line number, from 1, of line `i` of the section
+    pub fn line_no(&self, i: usize) -> usize {
+        self.offset + i + 1
+    }
+
+    pub fn header(&self) -> &str {
+        &self.lines[0]
+    }
+
+    /// The lines after the header
+    pub fn body(&self) -> &[String] {
+        &self.lines[1..]
+    }
+
+    /// Places an error from within this section, whose line numbers count from the
+    /// section's first line, in the input. Unlocated errors are put on the header line.
+    pub fn locate(&self, e: Error) -> Error {
+        if e.location().is_some() {
+            e.after_lines(self.offset)
+        } else {
+            e.at_line(self.line_no(0), self.header())
+        }
+    }
+
+    /// Parses each line
+    pub fn parse_lines<T>(&self) -> Result<Vec<T>, Error> where T: FromStr, Error: From<T::Err> {
+        parse_lines(self.lines.iter().map(String::as_str)).map_err(|e| self.locate(e))
+    }
+
+    /// Parses each line of the body
+    pub fn parse_body<T>(&self) -> Result<Vec<T>, Error> where T: FromStr, Error: From<T::Err> {
+        parse_lines(self.body().iter().map(String::as_str)).map_err(|e| self.locate(e.after_lines(1)))
+    }
+
+    /// The section as a grid of bytes, optionally padded
+    pub fn grid(&self, padding: Option<u8>) -> Result<Grid<u8>, Error> {
+        read_grid_ascii(&mut self.lines.join("\n").as_bytes(), padding).map_err(|e| self.locate(e))
+    }
+}
+
+/// Reads input a section at a time, sections being separated by one or more blank lines
+pub struct Sections<R> {
+    lines: Lines<R>,
+    line_count: usize,
+    index: usize,
+}
+
+impl<R: BufRead> Sections<R> {
+    pub fn new(reader: R) -> Self {
+        Self { lines: reader.lines(), line_count: 0, index: 0 }
+    }
+
+    /// The next section, failing if there isn't one with `what` was expected
+    pub fn expect(&mut self, what: &str) -> Result<Section, Error> {
+        self.next().unwrap_or_else(|| Err(aoc_err(format!("Expected {what} after line {}", self.line_count))))
+    }
+
+    /// Checks that no sections are left
+    pub fn end(mut self) -> Result<(), Error> {
+        match self.next().transpose()? {
+            Some(section) => {
+                let location = Location::new(section.line_no(0), section.header(), 0..section.header().len());
+                Err(aoc_err("Unexpected section").at(location))
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn read_section(&mut self) -> Result<Option<Section>, Error> {
+        let mut lines = Vec::new();
+        let mut offset = self.line_count;
+        for line in self.lines.by_ref() {
+            let line = line?;
+            self.line_count += 1;
+            if !line.trim().is_empty() {
+                lines.push(line);
+            } else if lines.is_empty() {
+                offset += 1;
+            } else {
+                break;
+            }
+        }
+
+        if lines.is_empty() {
+            return Ok(None);
+        }
+
+        let section = Section { index: self.index, offset, lines };
+        self.index += 1;
+        Ok(Some(section))
+    }
+}
+
+impl<R: BufRead> Iterator for Sections<R> {
+    type Item = Result<Section, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_section().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "seeds: 1 2\n\n\na map:\n1 2 3\n4 5 6\n\n#.#\n.x.\n\n";
+
+    #[test]
+    fn sections() {
+        let mut sections = Sections::new(INPUT.as_bytes());
+        let seeds = sections.expect("seeds").unwrap();
+        assert_eq!((seeds.index, seeds.offset, seeds.header()), (0, 0, "seeds: 1 2"));
+        assert!(seeds.body().is_empty());
+
+        let map = sections.expect("a map").unwrap();
+        assert_eq!((map.index, map.offset, map.header()), (1, 3, "a map:"));
+        assert_eq!(map.parse_body::<String>().unwrap(), ["1 2 3", "4 5 6"]);
+
+        let grid = sections.expect("a grid").unwrap();
+        assert_eq!((grid.index, grid.line_no(1)), (2, 9));
+        assert_eq!(grid.grid(None).unwrap().shape(), (2, 3));
+
+        assert!(sections.expect("more").is_err());
+        assert!(Sections::new("".as_bytes()).next().is_none());
+    }
+
+    #[test]
+    fn errors() {
+        let sections: Vec<_> = Sections::new(INPUT.as_bytes()).collect::<Result<_, _>>().unwrap();
+
+        let e = sections[1].parse_body::<u32>().unwrap_err();
+        assert_eq!(e.location(), Some(&Location::new(5, "1 2 3", 0..5)));
+
+        let e = sections[0].parse_lines::<u32>().unwrap_err();
+        assert_eq!(e.location().unwrap().line, 1);
+
+        let ragged = Sections::new("\n..\n...\n".as_bytes()).next().unwrap().unwrap();
+        let e = ragged.grid(None).unwrap_err();
+        assert_eq!(e.location(), Some(&Location::new(3, "...", 0..3)));
+
+        let mut sections = Sections::new(INPUT.as_bytes());
+        sections.expect("seeds").unwrap();
+        let e = sections.end().unwrap_err();
+        assert_eq!(e.location().unwrap().line, 4);
+    }
+}
@@ -1,10 +1,23 @@
 use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter};
-use num::Num;
+use std::ops;
+use std::ops::RangeInclusive;
+use num::{CheckedAdd, Integer, Num};
+
+use crate::error::{aoc_err, Error};
+
+mod boxed;
+mod interval_map;
+mod set;
+
+pub use boxed::Boxed;
+pub use interval_map::{IntervalMap, Offset};
+pub use set::RangeSet;
 
 pub trait Number: Num + PartialOrd + Copy + Debug {}
 impl<T: Num + PartialOrd + Copy + Debug> Number for T {}
 
+/// Half-open range `[start, end)` of any [`Number`], including signed and floating-point types
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Range<T> {
     start: T,
@@ -12,11 +25,23 @@ pub struct Range<T> {
 }
 
 impl<T: Number> Range<T> {
+    /// Panics unless `start <= end`; see [`try_new`](Self::try_new)
     pub fn new(start: T, end: T) -> Self {
-        assert!(start <= end);
+        assert!(start <= end, "Invalid range [{start:?}, {end:?})");
         Self { start, end }
     }
 
+    /// `[start, end)`, or an error unless `start <= end` (so never for NaN bounds)
+    pub fn try_new(start: T, end: T) -> Result<Self, Error> {
+        if start <= end {
+            Ok(Self { start, end })
+        } else {
+            Err(aoc_err(format!("Invalid range [{start:?}, {end:?})")))
+        }
+    }
+
+    /// `[start, start + len)`, panicking as [`new`](Self::new) on a negative length;
+    /// see [`try_from_start_len`](Self::try_from_start_len)
     pub fn from_start_len(start: T, len: T) -> Self {
         Self::new(start, start + len)
     }
@@ -57,12 +82,9 @@ impl<T: Number> Range<T> {
         self.intersection(other).is_some()
     }
 
-    pub fn split_at(&self, i: T) -> (Self, Self) {
-        if self.start <= i && i <= self.end {
-            (Self::new(self.start, i), Self::new(i, self.end))
-        } else {
-            panic!("split_at({:?}) out of range for {:?}", i, self);
-        }
+    /// `[start, i)` and `[i, end)`, if `i` is within the range or at its end
+    pub fn split_at(&self, i: T) -> Option<(Self, Self)> {
+        (self.start <= i && i <= self.end).then(|| (Self::new(self.start, i), Self::new(i, self.end)))
     }
 
     pub fn contains(&self, i: T) -> bool {
@@ -74,9 +96,52 @@ impl<T: Number> Range<T> {
     }
 }
 
+impl<T: Number + Integer + CheckedAdd> Range<T> {
+    /// `[start, last]`, panicking as [`new`](Self::new) unless `start <= last + 1`
+    pub fn inclusive(start: T, last: T) -> Self {
+        Self::new(start, last + T::one())
+    }
+
+    /// `[start, last]`, or an error unless `start <= last + 1` without overflow
+    pub fn try_inclusive(start: T, last: T) -> Result<Self, Error> {
+        let end = last.checked_add(&T::one())
+            .ok_or_else(|| aoc_err(format!("Range [{start:?}, {last:?}] too large")))?;
+        Self::try_new(start, end)
+    }
+
+    /// `[start, start + len)`, or an error if `len` is negative or the end overflows
+    pub fn try_from_start_len(start: T, len: T) -> Result<Self, Error> {
+        let end = start.checked_add(&len)
+            .ok_or_else(|| aoc_err(format!("Range of {len:?} from {start:?} too large")))?;
+        Self::try_new(start, end)
+    }
+
+    /// Highest value in the range
+    pub fn last(&self) -> Option<T> {
+        (!self.is_empty()).then(|| self.end - T::one())
+    }
+}
+
+impl<T: Number> TryFrom<ops::Range<T>> for Range<T> {
+    type Error = Error;
+
+    fn try_from(value: ops::Range<T>) -> Result<Self, Error> {
+        Self::try_new(value.start, value.end)
+    }
+}
+
+impl<T: Number + Integer + CheckedAdd> TryFrom<RangeInclusive<T>> for Range<T> {
+    type Error = Error;
+
+    fn try_from(value: RangeInclusive<T>) -> Result<Self, Error> {
+        let (start, last) = value.into_inner();
+        Self::try_inclusive(start, last)
+    }
+}
+
 impl<T: std::iter::Step> IntoIterator for Range<T> {
     type Item = T;
-    type IntoIter = std::ops::Range<T>;
+    type IntoIter = ops::Range<T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.start..self.end
@@ -88,3 +153,46 @@ impl<T: Display> Display for Range<T> {
         write!(f, "[{}, {})", self.start, self.end)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_ranges() {
+        assert_eq!(Range::try_new(3, 5).unwrap(), Range::new(3, 5));
+        assert!(Range::try_new(5, 3).is_err());
+        assert!(Range::try_new(0.0, f64::NAN).is_err());
+        assert_eq!(Range::inclusive(1u64, 4000), Range::new(1, 4001));
+        assert_eq!(Range::try_inclusive(4, 3).map(|r| r.is_empty()).ok(), Some(true));
+        assert!(Range::try_inclusive(0, u8::MAX).is_err());
+        assert!(Range::try_inclusive(5, 3).is_err());
+        assert_eq!(Range::try_from_start_len(3, 2).unwrap(), Range::new(3, 5));
+        assert!(Range::try_from_start_len(1, u64::MAX).is_err());
+        assert!(Range::try_from_start_len(3, -1).is_err());
+        assert_eq!(Range::try_from(-3..=-1).unwrap().last(), Some(-1));
+        assert_eq!(Range::new(2, 2).last(), None);
+        assert!(Range::try_from(ops::Range { start: 2, end: 1 }).is_err());
+    }
+
+    #[test]
+    fn signed_ranges() {
+        let r = Range::new(-5i64, 2);
+        assert_eq!(r.len(), 7);
+        assert_eq!(r.split_at(-2), Some((Range::new(-5, -2), Range::new(-2, 2))));
+        assert_eq!(r.split_at(2), Some((r, Range::new(2, 2))));
+        assert_eq!(r.split_at(-6), None);
+        assert_eq!(Range::new(-2, 1).into_iter().collect::<Vec<_>>(), [-2, -1, 0]);
+        assert_eq!(r.intersection(&Range::new(-10, -4)), Some(Range::new(-5, -4)));
+    }
+
+    #[test]
+    fn float_ranges() {
+        let r = Range::new(-1.5, 2.5);
+        assert_eq!(r.len(), 4.0);
+        assert!(r.contains(-1.5) && r.contains(2.4) && !r.contains(2.5));
+        assert_eq!(r.split_at(0.25), Some((Range::new(-1.5, 0.25), Range::new(0.25, 2.5))));
+        assert_eq!(r.split_at(f64::NAN), None);
+        assert_eq!(r.intersection(&Range::new(2.0, 3.0)), Some(Range::new(2.0, 2.5)));
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use crate::range::{Number, Range};
+
+/// An `N`-dimensional box: the product of one [`Range`] per axis
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Boxed<T, const N: usize> {
+    ranges: [Range<T>; N],
+}
+
+impl<T: Number, const N: usize> Boxed<T, N> {
+    pub const fn new(ranges: [Range<T>; N]) -> Self {
+        Self { ranges }
+    }
+
+    /// The same range along every axis
+    pub const fn uniform(range: Range<T>) -> Self {
+        Self { ranges: [range; N] }
+    }
+
+    pub const fn ranges(&self) -> &[Range<T>; N] {
+        &self.ranges
+    }
+
+    pub fn volume(&self) -> T {
+        self.ranges.iter().fold(T::one(), |volume, r| volume * r.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.iter().any(Range::is_empty)
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.ranges.iter().zip(point).all(|(r, i)| r.contains(i))
+    }
+
+    /// Whether all of `other` lies within this box
+    pub fn contains_box(&self, other: &Self) -> bool {
+        other.is_empty() || self.ranges.iter().zip(&other.ranges)
+            .all(|(r, o)| r.start <= o.start && o.end <= r.end)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut ranges = self.ranges;
+        for (r, o) in ranges.iter_mut().zip(&other.ranges) {
+            *r = r.intersection(o)?;
+        }
+        Some(Self { ranges })
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// This box with `range` along `axis` instead
+    pub fn with_axis(&self, axis: usize, range: Range<T>) -> Self {
+        let mut result = *self;
+        result.ranges[axis] = range;
+        result
+    }
+
+    /// The parts of the box below and from `i` along `axis`. Values outside the box's
+    /// range leave one part empty.
+    pub fn split_at(&self, axis: usize, i: T) -> (Self, Self) {
+        let range = self.ranges[axis];
+        let i = Range::min(Range::max(i, range.start), range.end);
+        let (below, above) = range.split_at(i).unwrap();
+        (self.with_axis(axis, below), self.with_axis(axis, above))
+    }
+
+    /// Disjoint boxes covering the parts of this box outside `other`
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        let Some(inter) = self.intersection(other) else {
+            return if self.is_empty() { vec![] } else { vec![*self] };
+        };
+
+        // Slice off the parts beyond the intersection one axis at a time
+        let mut result = Vec::new();
+        let mut rest = *self;
+        for axis in 0..N {
+            let (r, i) = (rest.ranges[axis], inter.ranges[axis]);
+            if r.start < i.start {
+                result.push(rest.with_axis(axis, Range::new(r.start, i.start)));
+            }
+            if i.end < r.end {
+                result.push(rest.with_axis(axis, Range::new(i.end, r.end)));
+            }
+            rest.ranges[axis] = i;
+        }
+        result
+    }
+}
+
+impl<T, const N: usize> Index<usize> for Boxed<T, N> {
+    type Output = Range<T>;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.ranges[axis]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Boxed<T, N> {
+    fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+        &mut self.ranges[axis]
+    }
+}
+
+impl<T: Display, const N: usize> Display for Boxed<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, range) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, " × ")?;
+            }
+            write!(f, "{range}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    fn square(x: (i32, i32), y: (i32, i32)) -> Boxed<i32, 2> {
+        Boxed::new([Range::new(x.0, x.1), Range::new(y.0, y.1)])
+    }
+
+    #[test]
+    fn boxes() {
+        let a = square((0, 4), (0, 3));
+        assert_eq!(a.volume(), 12);
+        assert!(a.contains([3, 2]) && !a.contains([4, 0]));
+        assert_eq!(a.intersection(&square((2, 6), (-1, 1))), Some(square((2, 4), (0, 1))));
+        assert_eq!(a.intersection(&square((4, 6), (0, 3))), None);
+        assert!(a.contains_box(&square((1, 2), (0, 3))));
+        assert!(!a.contains_box(&square((1, 5), (0, 3))));
+        assert_eq!(a.to_string(), "[0, 4) × [0, 3)");
+        assert_eq!(Boxed::<u64, 4>::uniform(Range::new(1, 4001)).volume(), 4000u64.pow(4));
+    }
+
+    #[test]
+    fn splits() {
+        let a = square((0, 4), (0, 3));
+        assert_eq!(a.split_at(0, 1), (square((0, 1), (0, 3)), square((1, 4), (0, 3))));
+        let (below, above) = a.split_at(1, 10);
+        assert_eq!(below, a);
+        assert!(above.is_empty());
+    }
+
+    #[test]
+    fn differences() {
+        let a = square((0, 4), (0, 4));
+        let hole = square((1, 3), (1, 2));
+        let rest = a.difference(&hole);
+        assert_eq!(rest.len(), 4);
+        assert_eq!(rest.iter().map(Boxed::volume).sum::<i32>(), 16 - 2);
+        assert!(rest.iter().tuple_combinations().all(|(p, q)| !p.intersects(q)));
+        assert!(rest.iter().all(|p| !p.intersects(&hole) && a.contains_box(p)));
+
+        assert_eq!(a.difference(&square((5, 6), (0, 1))), [a]);
+        assert!(hole.difference(&a).is_empty());
+    }
+}
@@ -0,0 +1,233 @@
+use num::traits::{WrappingAdd, WrappingSub};
+
+use crate::range::{Number, Range, RangeSet};
+
+/// Disjoint ranges of keys, each with a value: a piecewise function
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct IntervalMap<K, V> {
+    // Sorted and non-empty
+    entries: Vec<(Range<K>, V)>,
+}
+
+impl<K: Number, V> IntervalMap<K, V> {
+    pub const fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    /// Number of ranges
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item=(Range<K>, &V)> + ExactSizeIterator + '_ {
+        self.entries.iter().map(|(range, value)| (*range, value))
+    }
+
+    /// The range holding `key`, with its value
+    pub fn get_entry(&self, key: K) -> Option<(Range<K>, &V)> {
+        let ix = self.entries.partition_point(|(range, _)| range.end <= key);
+        self.entries.get(ix)
+            .filter(|(range, _)| range.contains(key))
+            .map(|(range, value)| (*range, value))
+    }
+
+    pub fn get(&self, key: K) -> Option<&V> {
+        self.get_entry(key).map(|(_, value)| value)
+    }
+
+    /// All keys with a value
+    pub fn domain(&self) -> RangeSet<K> {
+        self.entries.iter().map(|(range, _)| *range).collect()
+    }
+
+    /// Sets the value for every key in `range`, replacing any values there
+    pub fn insert(&mut self, range: Range<K>, value: V) where V: Clone {
+        if range.is_empty() {
+            return;
+        }
+
+        let first = self.entries.partition_point(|(r, _)| r.end <= range.start);
+        let last = self.entries.partition_point(|(r, _)| r.start < range.end);
+        // Only the first and last overlapping entries can stick out either side
+        let mut replacement = Vec::with_capacity(3);
+        if let Some((r, v)) = self.entries[first..last].first() {
+            if r.start < range.start {
+                replacement.push((Range::new(r.start, range.start), v.clone()));
+            }
+        }
+        replacement.push((range, value));
+        if let Some((r, v)) = self.entries[first..last].last() {
+            if range.end < r.end {
+                replacement.push((Range::new(range.end, r.end), v.clone()));
+            }
+        }
+        self.entries.splice(first..last, replacement);
+    }
+
+    /// `ranges` cut where entries begin and end, each piece with its value if it has one
+    pub fn pieces(&self, ranges: &RangeSet<K>) -> Vec<(Range<K>, Option<&V>)> {
+        let mut pieces = Vec::new();
+        for range in ranges.iter() {
+            let mut start = range.start;
+            let mut ix = self.entries.partition_point(|(r, _)| r.end <= start);
+            while let Some((r, value)) = self.entries.get(ix).filter(|(r, _)| r.start < range.end) {
+                if start < r.start {
+                    pieces.push((Range::new(start, r.start), None));
+                    start = r.start;
+                }
+                let end = Range::min(r.end, range.end);
+                pieces.push((Range::new(start, end), Some(value)));
+                start = end;
+                ix += 1;
+            }
+            if start < range.end {
+                pieces.push((Range::new(start, range.end), None));
+            }
+        }
+        pieces
+    }
+}
+
+impl<K: Number, V> Default for IntervalMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Later entries replace earlier ones where they overlap
+impl<K: Number, V: Clone> FromIterator<(Range<K>, V)> for IntervalMap<K, V> {
+    fn from_iter<I: IntoIterator<Item=(Range<K>, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (range, value) in iter {
+            map.insert(range, value);
+        }
+        map
+    }
+}
+
+/// Translation by a fixed amount, wrapping so that unsigned keys can move down
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Offset<K>(pub K);
+
+impl<K: Number + WrappingAdd + WrappingSub> Offset<K> {
+    /// The offset taking `from` to `to`
+    pub fn between(from: K, to: K) -> Self {
+        Self(to.wrapping_sub(&from))
+    }
+
+    pub fn apply(&self, key: K) -> K {
+        key.wrapping_add(&self.0)
+    }
+
+    pub fn apply_range(&self, range: Range<K>) -> Range<K> {
+        Range::from_start_len(self.apply(range.start), range.len())
+    }
+
+    /// This offset followed by `next`
+    pub fn then(&self, next: &Self) -> Self {
+        Self(self.0.wrapping_add(&next.0))
+    }
+}
+
+/// A translation map, as in day 5. Keys outside every range map to themselves.
+impl<K: Number + WrappingAdd + WrappingSub> IntervalMap<K, Offset<K>> {
+    pub fn translate(&self, key: K) -> K {
+        self.get(key).map_or(key, |offset| offset.apply(key))
+    }
+
+    pub fn translate_ranges(&self, ranges: &RangeSet<K>) -> RangeSet<K> {
+        self.pieces(ranges).into_iter()
+            .map(|(piece, offset)| offset.map_or(piece, |offset| offset.apply_range(piece)))
+            .collect()
+    }
+
+    /// One map translating as this one followed by `next`
+    pub fn then(&self, next: &Self) -> Self {
+        let mut entries = Vec::new();
+        // Keys this map moves, then on through `next`
+        for (range, offset) in self.iter() {
+            let image = RangeSet::from(offset.apply_range(range));
+            for (piece, next_offset) in next.pieces(&image) {
+                let preimage = Range::from_start_len(piece.start.wrapping_sub(&offset.0), piece.len());
+                entries.push((preimage, next_offset.map_or(*offset, |next_offset| offset.then(next_offset))));
+            }
+        }
+
+        // Keys this map leaves alone
+        let untouched = next.domain().difference(&self.domain());
+        for (piece, next_offset) in next.pieces(&untouched) {
+            entries.extend(next_offset.map(|&next_offset| (piece, next_offset)));
+        }
+
+        entries.sort_by(|(a, _), (b, _)| a.start.partial_cmp(&b.start).unwrap());
+        let mut result: Vec<(Range<K>, Offset<K>)> = Vec::with_capacity(entries.len());
+        for (range, offset) in entries {
+            match result.last_mut() {
+                Some((last, last_offset)) if last.end == range.start && *last_offset == offset => last.end = range.end,
+                _ => result.push((range, offset)),
+            }
+        }
+        result.retain(|(_, offset)| offset.0 != K::zero());
+        Self { entries: result }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translation(entries: &[(i64, i64, i64)]) -> IntervalMap<i64, Offset<i64>> {
+        entries.iter()
+            .map(|&(src, dest, len)| (Range::from_start_len(src, len), Offset::between(src, dest)))
+            .collect()
+    }
+
+    #[test]
+    fn values() {
+        let mut map = IntervalMap::new();
+        map.insert(Range::new(0, 10), 'a');
+        map.insert(Range::new(20, 30), 'b');
+        map.insert(Range::new(5, 25), 'c');
+        let entries = map.iter().map(|(r, &v)| (r.start(), r.end(), v)).collect::<Vec<_>>();
+        assert_eq!(entries, [(0, 5, 'a'), (5, 25, 'c'), (25, 30, 'b')]);
+        assert_eq!(map.get(4), Some(&'a'));
+        assert_eq!(map.get(25), Some(&'b'));
+        assert_eq!(map.get(30), None);
+        assert_eq!(map.get_entry(7), Some((Range::new(5, 25), &'c')));
+
+        map.insert(Range::new(1, 2), 'd');
+        assert_eq!(map.len(), 5);
+        assert_eq!(map.domain(), RangeSet::from(Range::new(0, 30)));
+
+        let pieces = map.pieces(&RangeSet::from(Range::new(-1, 3)));
+        assert_eq!(pieces, [
+            (Range::new(-1, 0), None),
+            (Range::new(0, 1), Some(&'a')),
+            (Range::new(1, 2), Some(&'d')),
+            (Range::new(2, 3), Some(&'a')),
+        ]);
+    }
+
+    #[test]
+    fn translations() {
+        // Day 5's seed-to-soil and soil-to-fertilizer maps
+        let seed_soil = translation(&[(98, 50, 2), (50, 52, 48)]);
+        let soil_fertilizer = translation(&[(15, 0, 37), (52, 37, 2), (0, 39, 15)]);
+        assert_eq!([79, 14, 55, 13].map(|s| seed_soil.translate(s)), [81, 14, 57, 13]);
+
+        let seeds: RangeSet<i64> = [Range::new(79, 93), Range::new(97, 99)].into_iter().collect();
+        let soil = seed_soil.translate_ranges(&seeds);
+        assert_eq!(soil, [Range::new(50, 51), Range::new(81, 95), Range::new(99, 100)].into_iter().collect());
+
+        let seed_fertilizer = seed_soil.then(&soil_fertilizer);
+        for seed in -5..120 {
+            assert_eq!(seed_fertilizer.translate(seed), soil_fertilizer.translate(seed_soil.translate(seed)), "seed {seed}");
+        }
+        assert_eq!(seed_fertilizer.translate_ranges(&seeds), soil_fertilizer.translate_ranges(&soil));
+        assert!(seed_fertilizer.iter().all(|(_, offset)| offset.0 != 0));
+    }
+}
@@ -0,0 +1,214 @@
+use std::fmt::{Display, Formatter};
+
+use crate::range::{Number, Range};
+
+/// A set of values as sorted, disjoint, non-adjacent and non-empty ranges
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Number> RangeSet<T> {
+    pub const fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    // Sorts and coalesces `ranges`, dropping empty ones
+    fn normalised(mut ranges: Vec<Range<T>>) -> Self {
+        ranges.retain(|r| !r.is_empty());
+        ranges.sort_by(|a, b| a.start.partial_cmp(&b.start).unwrap());
+
+        let mut result: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match result.last_mut() {
+                Some(last) if range.start <= last.end => last.end = Range::max(last.end, range.end),
+                _ => result.push(range),
+            }
+        }
+        Self { ranges: result }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item=Range<T>> + ExactSizeIterator + '_ {
+        self.ranges.iter().copied()
+    }
+
+    /// Number of separate ranges
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Number of values, summed over the ranges
+    pub fn total_len(&self) -> T {
+        self.ranges.iter().fold(T::zero(), |total, r| total + r.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The lowest value
+    pub fn start(&self) -> Option<T> {
+        self.ranges.first().map(Range::start)
+    }
+
+    /// One past the highest value
+    pub fn end(&self) -> Option<T> {
+        self.ranges.last().map(Range::end)
+    }
+
+    /// The smallest range covering the whole set
+    pub fn span(&self) -> Option<Range<T>> {
+        Some(Range::new(self.start()?, self.end()?))
+    }
+
+    pub fn contains(&self, i: T) -> bool {
+        // First range ending after `i` is the only one that can hold it
+        let ix = self.ranges.partition_point(|r| r.end <= i);
+        self.ranges.get(ix).is_some_and(|r| r.contains(i))
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        // Ranges touching `range` are merged with it
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = self.ranges[first..last].iter()
+            .fold(range, |acc, r| Range::new(Range::min(acc.start, r.start), Range::max(acc.end, r.end)));
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalised(self.iter().chain(other.iter()).collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut result = Vec::new();
+        let (mut a, mut b) = (self.ranges.iter().peekable(), other.ranges.iter().peekable());
+        while let (Some(ra), Some(rb)) = (a.peek(), b.peek()) {
+            result.extend(ra.intersection(rb));
+            // Whichever ends first can't meet anything further in the other set
+            if ra.end <= rb.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { ranges: result }
+    }
+
+    /// Values in this set but not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        match self.span() {
+            Some(span) => self.intersection(&other.complement(span)),
+            None => Self::new(),
+        }
+    }
+
+    /// Values within `bounds` not in this set
+    pub fn complement(&self, bounds: Range<T>) -> Self {
+        let mut result = Vec::new();
+        let mut start = bounds.start;
+        for range in &self.ranges {
+            if range.end <= bounds.start {
+                continue;
+            }
+            if range.start >= bounds.end {
+                break;
+            }
+            if start < range.start {
+                result.push(Range::new(start, range.start));
+            }
+            start = Range::max(start, range.end);
+        }
+        if start < bounds.end {
+            result.push(Range::new(start, bounds.end));
+        }
+        Self { ranges: result }
+    }
+}
+
+impl<T: Number> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Number> From<Range<T>> for RangeSet<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::normalised(vec![range])
+    }
+}
+
+impl<T: Number> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item=Range<T>>>(iter: I) -> Self {
+        Self::normalised(iter.into_iter().collect())
+    }
+}
+
+impl<T: Number> Extend<Range<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item=Range<T>>>(&mut self, iter: I) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.extend(iter);
+        *self = Self::normalised(ranges);
+    }
+}
+
+impl<T: Display> Display for RangeSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for (i, range) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{range}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(i32, i32)]) -> RangeSet<i32> {
+        ranges.iter().map(|&(start, end)| Range::new(start, end)).collect()
+    }
+
+    #[test]
+    fn coalescing() {
+        let s = set(&[(5, 7), (0, 2), (2, 3), (6, 9), (4, 4)]);
+        assert_eq!(s.ranges(), set(&[(0, 3), (5, 9)]).ranges());
+        assert_eq!(s.range_count(), 2);
+        assert_eq!(s.total_len(), 7);
+        assert_eq!(s.span(), Some(Range::new(0, 9)));
+        assert!(s.contains(2) && s.contains(5) && !s.contains(3) && !s.contains(9));
+        assert_eq!(s.to_string(), "{[0, 3), [5, 9)}");
+
+        let mut s = s;
+        s.insert(Range::new(3, 5));
+        assert_eq!(s, set(&[(0, 9)]));
+        s.insert(Range::new(-4, -2));
+        s.insert(Range::new(10, 11));
+        assert_eq!(s, set(&[(-4, -2), (0, 9), (10, 11)]));
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = set(&[(0, 5), (10, 15)]);
+        let b = set(&[(3, 12), (14, 20)]);
+        assert_eq!(a.union(&b), set(&[(0, 20)]));
+        assert_eq!(a.intersection(&b), set(&[(3, 5), (10, 12), (14, 15)]));
+        assert_eq!(a.difference(&b), set(&[(0, 3), (12, 14)]));
+        assert_eq!(b.difference(&a), set(&[(5, 10), (15, 20)]));
+        assert_eq!(a.complement(Range::new(-5, 12)), set(&[(-5, 0), (5, 10)]));
+        assert_eq!(RangeSet::new().complement(Range::new(1, 2)), set(&[(1, 2)]));
+        assert!(a.difference(&a).is_empty());
+    }
+}
@@ -0,0 +1,240 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::{iter, mem};
+
+use fxhash::FxBuildHasher;
+use indexmap::IndexMap;
+use indexmap::map::Entry;
+use num::Zero;
+
+/// The cheapest way found from a start state to an end state, both included
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+impl<S, C> Path<S, C> {
+    pub fn start(&self) -> &S {
+        &self.states[0]
+    }
+
+    pub fn end(&self) -> &S {
+        &self.states[self.states.len() - 1]
+    }
+}
+
+const NO_PARENT: usize = usize::MAX;
+
+// Each state seen, by index, with the index of the state it was reached from and its cost
+type Seen<S, C> = IndexMap<S, (usize, C), FxBuildHasher>;
+
+fn path_to<S: Clone, C: Copy>(seen: &Seen<S, C>, end_ix: usize) -> Path<S, C> {
+    let cost = seen[end_ix].1;
+    let mut states = Vec::new();
+    let mut ix = end_ix;
+    while ix != NO_PARENT {
+        let (state, &(parent_ix, _)) = seen.get_index(ix).unwrap();
+        states.push(state.clone());
+        ix = parent_ix;
+    }
+    states.reverse();
+    Path { cost, states }
+}
+
+/// Breadth-first search for the fewest steps from any of `starts` to a state
+/// satisfying `is_end`
+pub fn bfs<S, I, N, E>(starts: impl IntoIterator<Item=S>, mut neighbours: N, mut is_end: E) -> Option<Path<S, usize>>
+    where
+        S: Clone + Eq + Hash,
+        N: FnMut(&S) -> I,
+        I: IntoIterator<Item=S>,
+        E: FnMut(&S) -> bool,
+{
+    let mut seen: Seen<S, usize> = Seen::default();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let (ix, None) = seen.insert_full(start, (NO_PARENT, 0)) {
+            queue.push_back(ix);
+        }
+    }
+
+    while let Some(ix) = queue.pop_front() {
+        let (state, &(_, steps)) = seen.get_index(ix).unwrap();
+        if is_end(state) {
+            return Some(path_to(&seen, ix));
+        }
+
+        for next in neighbours(&state.clone()) {
+            if let Entry::Vacant(e) = seen.entry(next) {
+                queue.push_back(e.index());
+                e.insert((ix, steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// States a breadth-first layer at a time: the starts, then the states first reached in
+/// one step, and so on, until no new states are reached
+pub fn bfs_layers<S, I, N>(starts: impl IntoIterator<Item=S>, mut neighbours: N) -> impl Iterator<Item=Vec<S>>
+    where
+        S: Clone + Eq + Hash,
+        N: FnMut(&S) -> I,
+        I: IntoIterator<Item=S>,
+{
+    let mut seen: HashSet<S, FxBuildHasher> = HashSet::default();
+    let mut layer: Vec<S> = starts.into_iter().filter(|start| seen.insert(start.clone())).collect();
+    iter::from_fn(move || {
+        if layer.is_empty() {
+            return None;
+        }
+
+        let next = layer.iter()
+            .flat_map(&mut neighbours)
+            .filter(|next| seen.insert(next.clone()))
+            .collect();
+        Some(mem::replace(&mut layer, next))
+    })
+}
+
+/// Dijkstra's algorithm: the cheapest path from any of `starts` to a state satisfying
+/// `is_end`, where `neighbours` gives each next state with the cost of moving to it
+pub fn dijkstra<S, C, I, N, E>(starts: impl IntoIterator<Item=S>, neighbours: N, is_end: E) -> Option<Path<S, C>>
+    where
+        S: Clone + Eq + Hash,
+        C: Copy + Ord + Zero,
+        N: FnMut(&S) -> I,
+        I: IntoIterator<Item=(S, C)>,
+        E: FnMut(&S) -> bool,
+{
+    astar(starts, neighbours, |_| C::zero(), is_end)
+}
+
+/// A* search: as [`dijkstra`], guided by `heuristic`, which must never overestimate the
+/// remaining cost to an end state
+pub fn astar<S, C, I, N, H, E>(starts: impl IntoIterator<Item=S>, mut neighbours: N, mut heuristic: H, mut is_end: E)
+    -> Option<Path<S, C>>
+    where
+        S: Clone + Eq + Hash,
+        C: Copy + Ord + Zero,
+        N: FnMut(&S) -> I,
+        I: IntoIterator<Item=(S, C)>,
+        H: FnMut(&S) -> C,
+        E: FnMut(&S) -> bool,
+{
+    let mut seen: Seen<S, C> = Seen::default();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let (ix, None) = seen.insert_full(start, (NO_PARENT, C::zero())) {
+            queue.push(Reverse((estimate, C::zero(), ix)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, ix))) = queue.pop() {
+        let (state, &(_, best_cost)) = seen.get_index(ix).unwrap();
+        if cost > best_cost {
+            // Superseded by a cheaper way here
+            continue;
+        }
+
+        if is_end(state) {
+            return Some(path_to(&seen, ix));
+        }
+
+        for (next, step_cost) in neighbours(&state.clone()) {
+            let next_cost = cost + step_cost;
+            let next_ix = match seen.entry(next) {
+                Entry::Vacant(e) => {
+                    let next_ix = e.index();
+                    e.insert((ix, next_cost));
+                    next_ix
+                }
+                Entry::Occupied(mut e) if next_cost < e.get().1 => {
+                    e.insert((ix, next_cost));
+                    e.index()
+                }
+                Entry::Occupied(_) => continue,
+            };
+
+            let estimate = next_cost + heuristic(seen.get_index(next_ix).unwrap().0);
+            queue.push(Reverse((estimate, next_cost, next_ix)));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use crate::grid::{read_grid_ascii, Grid, Way};
+
+    const MAZE: &str = "S.#.\n.##.\n...E\n";
+
+    fn maze() -> Grid<u8> {
+        read_grid_ascii(&mut Cursor::new(MAZE), None).unwrap()
+    }
+
+    fn open_neighbours(grid: &Grid<u8>, pos: (usize, usize)) -> impl Iterator<Item=(usize, usize)> + '_ {
+        Way::all().iter()
+            .filter_map(move |way| grid.step(pos, way))
+            .filter(|&next| grid[next] != b'#')
+    }
+
+    #[test]
+    fn bfs_path() {
+        let grid = maze();
+        let path = bfs([(0, 0)], |&pos| open_neighbours(&grid, pos), |&pos| grid[pos] == b'E').unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.states, [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (2, 3)]);
+        assert_eq!((*path.start(), *path.end()), ((0, 0), (2, 3)));
+
+        // Nearest of several starts
+        let path = bfs([(0, 0), (0, 3)], |&pos| open_neighbours(&grid, pos), |&pos| grid[pos] == b'E').unwrap();
+        assert_eq!(path.states, [(0, 3), (1, 3), (2, 3)]);
+
+        assert_eq!(bfs([(0, 0)], |&pos| open_neighbours(&grid, pos), |_| false), None);
+    }
+
+    #[test]
+    fn layers() {
+        let grid = maze();
+        let mut layers = bfs_layers([(0, 0), (0, 0)], |&pos| open_neighbours(&grid, pos)).collect::<Vec<_>>();
+        layers[1].sort();
+        assert_eq!(layers.len(), 8);
+        assert_eq!(layers[..3], [vec![(0, 0)], vec![(0, 1), (1, 0)], vec![(2, 0)]]);
+        assert_eq!(layers[7], [(0, 3)]);
+    }
+
+    #[test]
+    fn weighted_paths() {
+        // a -1-> b -1-> c, and a -5-> c
+        let neighbours = |&s: &char| match s {
+            'a' => vec![('c', 5), ('b', 1)],
+            'b' => vec![('c', 1)],
+            _ => vec![],
+        };
+
+        let path = dijkstra(['a'], neighbours, |&s| s == 'c').unwrap();
+        assert_eq!(path, Path { cost: 2, states: vec!['a', 'b', 'c'] });
+        assert_eq!(dijkstra(['c'], neighbours, |&s| s == 'a'), None);
+        assert_eq!(dijkstra(['a'], neighbours, |&s| s == 'a').unwrap().states, ['a']);
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let grid = maze();
+        let end = (2, 3);
+        let neighbours = |&pos: &(usize, usize)| open_neighbours(&grid, pos).map(|next| (next, 1));
+        let manhattan = |&(r, c): &(usize, usize)| r.abs_diff(end.0) + c.abs_diff(end.1);
+
+        let path = astar([(0, 0)], neighbours, manhattan, |&pos| pos == end).unwrap();
+        assert_eq!(path, dijkstra([(0, 0)], neighbours, |&pos| pos == end).unwrap());
+        assert_eq!(path.cost, 5);
+    }
+}
@@ -0,0 +1,121 @@
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::env;
+use std::process::ExitCode;
+
+use crate::error::{aoc_err, Error};
+use crate::input::InputConfig;
+
+/// A day's puzzle solution: parse the input once, then answer each part from it.
+pub trait Solution {
+    const DAY: u8;
+    /// Puzzle input compiled into the solution, used instead of a data file
+    const INPUT: Option<&'static str> = None;
+    const PARTS: u8 = 2;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, Error>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, Error>;
+}
+
+/// Answer type for a part with no puzzle (day 25 part 2)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoAnswer {}
+
+impl Display for NoAnswer {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {}
+    }
+}
+
+/// Object-safe view of a [`Solution`], so that days can be run from a table.
+pub trait Puzzle {
+    fn day(&self) -> u8;
+    fn builtin_input(&self) -> Option<&'static str>;
+    fn parts(&self) -> u8;
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, Error>;
+    fn part1(&self, input: &dyn Any) -> Result<String, Error>;
+    fn part2(&self, input: &dyn Any) -> Result<String, Error>;
+
+    fn name(&self) -> String {
+        format!("day-{:02}", self.day())
+    }
+
+    fn part(&self, part: u8, input: &dyn Any) -> Result<String, Error> {
+        match part {
+            1 => self.part1(input),
+            2 => self.part2(input),
+            _ => Err(aoc_err(format!("No part {part}"))),
+        }
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> Result<&S::Input, Error> where S::Input: 'static {
+    input.downcast_ref().ok_or_else(|| aoc_err(format!("Input is not for day {}", S::DAY)))
+}
+
+impl<S> Puzzle for S where S: Solution, S::Input: 'static {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn builtin_input(&self) -> Option<&'static str> {
+        S::INPUT
+    }
+
+    fn parts(&self) -> u8 {
+        S::PARTS
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, Error> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn part1(&self, input: &dyn Any) -> Result<String, Error> {
+        S::part1(downcast::<S>(input)?).map(|answer| answer.to_string())
+    }
+
+    fn part2(&self, input: &dyn Any) -> Result<String, Error> {
+        S::part2(downcast::<S>(input)?).map(|answer| answer.to_string())
+    }
+}
+
+/// Runs every part of `puzzle`, calling `report` with each part number and answer.
+pub fn run<F>(puzzle: &dyn Puzzle, input: &str, mut report: F) -> Result<(), Error>
+    where F: FnMut(u8, String)
+{
+    let parsed = puzzle.parse(input)?;
+    for part in 1..=puzzle.parts() {
+        report(part, puzzle.part(part, parsed.as_ref())?);
+    }
+
+    Ok(())
+}
+
+/// Entry point for a single day's binary, taking an optional input file argument (`-` for stdin).
+/// Errors are printed with their location, and the exit status is then a failure.
+pub fn main(puzzle: &dyn Puzzle) -> ExitCode {
+    match run_main(puzzle) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}: {e}", puzzle.name());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_main(puzzle: &dyn Puzzle) -> Result<(), Error> {
+    let mut config = InputConfig::from_env();
+    if let Some(arg) = env::args().nth(1) {
+        config.source = Some(arg.parse()?);
+    }
+
+    let source = config.resolve(puzzle)?;
+    let input = source.read()?;
+    run(puzzle, &input, |part, answer| println!("Part {part}: {answer}"))
+        .map_err(|e| e.in_source(&source.name()))
+}
@@ -0,0 +1,210 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+use crate::error::{aoc_err, Error};
+use crate::solution::Puzzle;
+
+/// Recorded in place of an answer when solving the part should fail
+pub const EXPECT_ERROR: &str = "error";
+
+/// Known-good answers for one input file, in the same `Part N: answer` form the solutions print.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<u8, String>);
+
+impl Answers {
+    /// Reads the answers recorded for `input_path`, if there are any.
+    pub fn read_for(input_path: &Path) -> Result<Option<Self>, Error> {
+        match fs::read_to_string(answers_path(input_path)) {
+            Ok(s) => Ok(Some(s.parse()?)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        self.0.get(&part).map(String::as_str)
+    }
+}
+
+impl FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = BTreeMap::new();
+        for line in s.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#')) {
+            let (part, answer) = line.strip_prefix("Part ")
+                .and_then(|rest| rest.split_once(':'))
+                .ok_or_else(|| aoc_err(format!("Expected 'Part N: answer', got '{line}'")))?;
+
+            answers.insert(part.trim().parse()?, answer.trim().to_owned());
+        }
+
+        Ok(Self(answers))
+    }
+}
+
+/// Answers for `data/day-NN.txt` live in `data/day-NN.answers`
+pub fn answers_path(input_path: &Path) -> PathBuf {
+    input_path.with_extension("answers")
+}
+
+#[derive(Debug)]
+pub enum Verdict {
+    Pass,
+    Mismatch { expected: String, actual: String },
+    Fail(Error),
+    /// Never a pass, even where an error is expected
+    Panicked(String),
+    Unverified { actual: String },
+}
+
+impl Verdict {
+    pub fn is_ok(&self) -> bool {
+        !matches!(self, Self::Mismatch { .. } | Self::Fail(_) | Self::Panicked(_))
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Mismatch { expected, actual } => write!(f, "mismatch: expected {expected}, got {actual}"),
+            Self::Fail(e) => write!(f, "fail: {e}"),
+            Self::Panicked(message) => write!(f, "panicked: {message}"),
+            Self::Unverified { actual } => write!(f, "no recorded answer, got {actual}"),
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(s) => *s,
+        Err(payload) => payload.downcast_ref::<&str>().copied().unwrap_or("unknown cause").to_owned(),
+    }
+}
+
+/// Runs `f`, catching a panic so one bad input can't stop the rest. The outer error is
+/// the panic message, kept apart from errors that `f` returns.
+fn no_panic<T>(f: impl FnOnce() -> Result<T, Error>) -> Result<Result<T, Error>, String> {
+    catch_unwind(AssertUnwindSafe(f)).map_err(panic_message)
+}
+
+/// Solves each part of `puzzle` and checks the result against `answers`.
+/// Fails only if the input cannot be parsed, otherwise every part gets a verdict.
+pub fn verify(puzzle: &dyn Puzzle, input: &str, answers: &Answers) -> Result<Vec<(u8, Verdict)>, Error> {
+    let parsed = no_panic(|| puzzle.parse(input))
+        .unwrap_or_else(|message| Err(aoc_err(format!("Panicked: {message}"))))?;
+    let verdicts = (1..=puzzle.parts())
+        .map(|part| {
+            let verdict = match (no_panic(|| puzzle.part(part, parsed.as_ref())), answers.get(part)) {
+                (Err(message), _) => Verdict::Panicked(message),
+                (Ok(Err(_)), Some(EXPECT_ERROR)) => Verdict::Pass,
+                (Ok(Err(e)), _) => Verdict::Fail(e),
+                (Ok(Ok(actual)), None) => Verdict::Unverified { actual },
+                (Ok(Ok(actual)), Some(expected)) if actual == expected => Verdict::Pass,
+                (Ok(Ok(actual)), Some(expected)) => Verdict::Mismatch { expected: expected.to_owned(), actual },
+            };
+            (part, verdict)
+        })
+        .collect();
+
+    Ok(verdicts)
+}
+
+/// Verifies `puzzle` against each `<name>.txt` input in `dir` and its `<name>.answers`,
+/// returning a description of every failure.
+pub fn verify_dir(puzzle: &dyn Puzzle, dir: &Path) -> Result<Vec<String>, Error> {
+    let inputs = fs::read_dir(dir)?
+        .map_ok(|entry| entry.path())
+        .filter_ok(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut failures = Vec::new();
+    for path in inputs.into_iter().sorted() {
+        let name = path.file_stem().unwrap_or_default().to_string_lossy();
+        let Some(answers) = Answers::read_for(&path)? else {
+            failures.push(format!("{name}: no {}", answers_path(&path).display()));
+            continue;
+        };
+
+        match verify(puzzle, &fs::read_to_string(&path)?, &answers) {
+            Ok(verdicts) => failures.extend(verdicts.into_iter()
+                .filter(|(_, verdict)| !verdict.is_ok())
+                .map(|(part, verdict)| format!("{name} part {part}: {verdict}"))),
+            Err(e) => failures.push(format!("{name}: {e}")),
+        }
+    }
+
+    Ok(failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Solution;
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        const DAY: u8 = 1;
+
+        type Input = Vec<usize>;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, Error> {
+            Ok(input.lines().map(str::len).collect())
+        }
+
+        fn part1(input: &Self::Input) -> Result<usize, Error> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> Result<usize, Error> {
+            match input.first() {
+                Some(&0) => panic!("Blank first line"),
+                _ => input.iter().max().copied().ok_or_else(|| aoc_err("Empty input")),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_answers() {
+        let answers: Answers = "# Comment\nPart 1: 54450\n\nPart 2:  abc \n".parse().unwrap();
+        assert_eq!(answers.get(1), Some("54450"));
+        assert_eq!(answers.get(2), Some("abc"));
+        assert_eq!(answers.get(3), None);
+        assert!("Part one: 2".parse::<Answers>().is_err());
+        assert!("54450".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn verdicts() {
+        let answers: Answers = "Part 1: 5\nPart 2: 4".parse().unwrap();
+        let verdicts = verify(&Lengths, "abc\nde\n", &answers).unwrap();
+        assert!(matches!(verdicts[0], (1, Verdict::Pass)));
+        assert!(matches!(&verdicts[1], (2, Verdict::Mismatch { expected, actual }) if expected == "4" && actual == "3"));
+
+        let verdicts = verify(&Lengths, "", &Answers::default()).unwrap();
+        assert!(matches!(&verdicts[0], (1, Verdict::Unverified { actual }) if actual == "0"));
+        assert!(matches!(verdicts[1], (2, Verdict::Fail(_))));
+        assert!(!verdicts[1].1.is_ok());
+
+        let answers: Answers = "Part 2: error".parse().unwrap();
+        let verdicts = verify(&Lengths, "", &answers).unwrap();
+        assert!(matches!(verdicts[1], (2, Verdict::Pass)));
+
+        // A panic isn't the error expected
+        let verdicts = verify(&Lengths, "\nabc\n", &answers).unwrap();
+        assert!(matches!(&verdicts[1], (2, Verdict::Panicked(message)) if message == "Blank first line"));
+        assert!(!verdicts[1].1.is_ok());
+    }
+}
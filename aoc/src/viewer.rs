@@ -0,0 +1,268 @@
+use std::time::{Duration, Instant};
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::DefaultTerminal;
+
+use crate::error::{aoc_err, Error};
+use crate::frames::Frame;
+use crate::grid::Way;
+
+const HELP: &str = " space play/pause · ←→ step · home/end · +- zoom · hjkl scroll (HJKL ×10) · q quit ";
+const MAX_ZOOM: usize = 4;
+
+/// What a key asks the viewer to do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    TogglePlay,
+    Step(isize),
+    First,
+    Last,
+    Zoom(isize),
+    Scroll(Way, usize),
+    Quit,
+}
+
+impl Command {
+    pub fn from_key(key: KeyEvent) -> Option<Self> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Some(Command::Quit);
+        }
+
+        let command = match key.code {
+            KeyCode::Char(' ') => Command::TogglePlay,
+            KeyCode::Right | KeyCode::Char('.') => Command::Step(1),
+            KeyCode::Left | KeyCode::Char(',') => Command::Step(-1),
+            KeyCode::PageDown => Command::Step(10),
+            KeyCode::PageUp => Command::Step(-10),
+            KeyCode::Home => Command::First,
+            KeyCode::End => Command::Last,
+            KeyCode::Char('+' | '=') => Command::Zoom(1),
+            KeyCode::Char('-') => Command::Zoom(-1),
+            KeyCode::Char(c @ ('h' | 'j' | 'k' | 'l' | 'H' | 'J' | 'K' | 'L')) => {
+                let way = match c.to_ascii_lowercase() {
+                    'h' => Way::Left,
+                    'j' => Way::Down,
+                    'k' => Way::Up,
+                    _ => Way::Right,
+                };
+                Command::Scroll(way, if c.is_ascii_uppercase() { 10 } else { 1 })
+            }
+            KeyCode::Up => Command::Scroll(Way::Up, 1),
+            KeyCode::Down => Command::Scroll(Way::Down, 1),
+            KeyCode::Char('q') | KeyCode::Esc => Command::Quit,
+            _ => return None,
+        };
+
+        Some(command)
+    }
+}
+
+/// Position in the frames and how they are shown
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewState {
+    pub frame: usize,
+    pub playing: bool,
+    /// Terminal columns per grid cell
+    pub zoom: usize,
+    /// Top left cell shown, as (row, column)
+    pub scroll: (usize, usize),
+    pub quit: bool,
+}
+
+impl Default for ViewState {
+    fn default() -> Self {
+        Self { frame: 0, playing: false, zoom: 1, scroll: (0, 0), quit: false }
+    }
+}
+
+impl ViewState {
+    /// Applies `command` to a recording of `count` frames
+    pub fn apply(&mut self, command: Command, count: usize) {
+        let last = count.saturating_sub(1);
+        match command {
+            Command::TogglePlay => {
+                // Playing from the end starts again
+                if !self.playing && self.frame == last {
+                    self.frame = 0;
+                }
+                self.playing = !self.playing;
+            }
+            Command::Step(n) => {
+                self.playing = false;
+                self.frame = self.frame.saturating_add_signed(n).min(last);
+            }
+            Command::First => self.frame = 0,
+            Command::Last => self.frame = last,
+            Command::Zoom(n) => self.zoom = self.zoom.saturating_add_signed(n).clamp(1, MAX_ZOOM),
+            Command::Scroll(way, n) => {
+                let (r, c) = self.scroll;
+                self.scroll = match way {
+                    Way::Up => (r.saturating_sub(n), c),
+                    Way::Down => (r + n, c),
+                    Way::Left => (r, c.saturating_sub(n)),
+                    Way::Right => (r, c + n),
+                };
+            }
+            Command::Quit => self.quit = true,
+        }
+    }
+
+    /// Advances a playing recording, pausing at the end
+    pub fn tick(&mut self, count: usize) {
+        if self.playing {
+            if self.frame + 1 < count {
+                self.frame += 1;
+            } else {
+                self.playing = false;
+            }
+        }
+    }
+
+    /// Rows of `frame` as shown, starting from the scroll position. Cells of frames
+    /// with an image take its colours, and at higher zooms are padded into blocks.
+    pub fn lines(&self, frame: &Frame) -> Vec<Line<'static>> {
+        let (r0, c0) = self.scroll;
+        let row_height = self.zoom.div_ceil(2);
+        let mut lines = Vec::new();
+        for (r, text) in frame.text.lines().enumerate().skip(r0) {
+            let spans = text.chars().enumerate().skip(c0)
+                .map(|(c, chr)| {
+                    let style = cell_style(frame, (r, c));
+                    Span::styled(format!("{chr:<width$}", width = self.zoom), style)
+                })
+                .collect::<Vec<_>>();
+            lines.push(Line::from(spans.clone()));
+            for _ in 1..row_height {
+                let blank = spans.iter().map(|span| Span::styled(" ".repeat(self.zoom), span.style)).collect::<Vec<_>>();
+                lines.push(Line::from(blank));
+            }
+        }
+        lines
+    }
+}
+
+fn cell_style(frame: &Frame, (r, c): (usize, usize)) -> Style {
+    let Some(image) = &frame.image else {
+        return Style::default();
+    };
+
+    if r >= image.height || c >= image.width {
+        return Style::default();
+    }
+
+    let ix = (r * image.width + c) * 3;
+    let [red, green, blue] = [0, 1, 2].map(|i| image.pixels[ix + i]);
+    // Text in whichever of black or white stands out more
+    let luma = 299 * red as u32 + 587 * green as u32 + 114 * blue as u32;
+    let fg = if luma > 128_000 { Color::Black } else { Color::White };
+    Style::default().bg(Color::Rgb(red, green, blue)).fg(fg)
+}
+
+fn draw(terminal: &mut DefaultTerminal, frames: &[Frame], state: &ViewState) -> Result<(), Error> {
+    let frame = &frames[state.frame];
+    terminal.draw(|f| {
+        let [main, help] = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(f.area());
+        let status = if state.playing { "playing" } else { "paused" };
+        let title = format!(" {} · frame {}/{} · {status} · zoom {} ", frame.source, state.frame + 1, frames.len(), state.zoom);
+        f.render_widget(Paragraph::new(state.lines(frame)).block(Block::bordered().title(title)), main);
+        f.render_widget(Line::from(HELP).dim(), help);
+    })?;
+    Ok(())
+}
+
+fn run(terminal: &mut DefaultTerminal, frames: &[Frame], delay: Duration) -> Result<(), Error> {
+    let mut state = ViewState::default();
+    let mut next_tick = Instant::now() + delay;
+    while !state.quit {
+        draw(terminal, frames, &state)?;
+
+        let timeout = if state.playing { next_tick.saturating_duration_since(Instant::now()) } else { Duration::from_secs(60) };
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    if let Some(command) = Command::from_key(key) {
+                        state.apply(command, frames.len());
+                        next_tick = Instant::now() + delay;
+                    }
+                }
+            }
+        } else if state.playing {
+            state.tick(frames.len());
+            next_tick = Instant::now() + delay;
+        }
+    }
+
+    Ok(())
+}
+
+/// Shows `frames` in the terminal until quit, playing them with `delay` between frames
+pub fn view(frames: &[Frame], delay: Duration) -> Result<(), Error> {
+    if frames.is_empty() {
+        return Err(aoc_err("No frames to view"));
+    }
+
+    let mut terminal = ratatui::try_init()?;
+    let result = run(&mut terminal, frames, delay);
+    ratatui::try_restore()?;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frames::Image;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn text(lines: &[Line]) -> Vec<String> {
+        lines.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn commands() {
+        let mut state = ViewState::default();
+        let mut press = |code| state.apply(Command::from_key(key(code)).unwrap(), 3);
+        press(KeyCode::Right);
+        press(KeyCode::End);
+        press(KeyCode::Right);
+        press(KeyCode::Char('+'));
+        press(KeyCode::Char('L'));
+        press(KeyCode::Char('k'));
+        assert_eq!(state, ViewState { frame: 2, zoom: 2, scroll: (0, 10), ..ViewState::default() });
+
+        // Play restarts from the end, and stops there again
+        state.apply(Command::TogglePlay, 3);
+        assert_eq!((state.frame, state.playing), (0, true));
+        for _ in 0..3 {
+            state.tick(3);
+        }
+        assert_eq!((state.frame, state.playing), (2, false));
+
+        state.apply(Command::Zoom(-5), 3);
+        assert_eq!(state.zoom, 1);
+        assert_eq!(Command::from_key(key(KeyCode::Char('x'))), None);
+        assert_eq!(Command::from_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)), Some(Command::Quit));
+    }
+
+    #[test]
+    fn frame_lines() {
+        let frame = Frame::text("test", "abc\ndef\n");
+        let mut state = ViewState { scroll: (1, 1), ..ViewState::default() };
+        assert_eq!(text(&state.lines(&frame)), ["ef"]);
+
+        state.zoom = 3;
+        assert_eq!(text(&state.lines(&frame)), ["e  f  ", "      "]);
+
+        let image = Image { width: 1, height: 1, pixels: vec![255, 0, 0] };
+        let frame = Frame { image: Some(image), ..Frame::text("test", "ab") };
+        let lines = ViewState::default().lines(&frame);
+        assert_eq!(lines[0].spans[0].style.bg, Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(lines[0].spans[1].style.bg, None);
+    }
+}
@@ -1,9 +1,12 @@
 pub(crate) mod parse;
+mod solution;
 
 use std::cmp::max;
 use std::str::FromStr;
 use derive_builder::Builder;
-use nom::Finish;
+use aoc::parse::combinators::parse_all;
+
+pub use solution::Day02;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Builder)]
 #[builder(default)]
@@ -57,16 +60,9 @@ impl Game {
 }
 
 impl FromStr for Game {
-    type Err = nom::error::Error<String>;
+    type Err = aoc::error::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let result = parse::game(s)
-            .map_err(|e| e.to_owned())
-            .finish();
-
-        match result {
-            Ok((_input, game)) => Ok(game),
-            Err(e) => Err(e),
-        }
+        parse_all(s, parse::game)
     }
 }
@@ -1,21 +1,14 @@
-use nom::{IResult, Parser};
+use nom::IResult;
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::{char, space0, space1, u64 as nom_u64, u8 as nom_u8};
-use nom::combinator::{all_consuming, map, map_res};
-use nom::error::ParseError;
+use nom::combinator::{map, map_res};
 use nom::multi::separated_list1;
-use nom::sequence::{delimited, preceded, separated_pair, tuple};
+use nom::sequence::{preceded, separated_pair, tuple};
 
-use crate::{Cubes, CubesBuilder, CubesBuilderError, Game};
+use aoc::parse::combinators::trim;
 
-pub fn trim<'i, F, O, E>(parser: F) -> impl FnMut(&'i str) -> IResult<&'i str, O, E>
-    where
-        F: Parser<&'i str, O, E>,
-        E: ParseError<&'i str>
-{
-    all_consuming(delimited(space0, parser, space0))
-}
+use crate::{Cubes, CubesBuilder, CubesBuilderError, Game};
 
 fn rgb(input: &str) -> IResult<&str, &str> {
     alt((tag("red"), tag("green"), tag("blue")))(input)
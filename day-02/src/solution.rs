@@ -0,0 +1,81 @@
+use std::io::{BufRead, BufReader, Read};
+
+use aoc::parse::AtLine;
+use aoc::solution::Solution;
+
+use crate::{Cubes, Game};
+
+fn read_games<R: Read>(input: R) -> Result<Vec<Game>, aoc::error::Error> {
+    let lines = BufReader::new(input).lines();
+    let mut games = Vec::new();
+    for (i, line) in lines.enumerate() {
+        let line = line?;
+        games.push(line.parse().at_line(i + 1, &line)?);
+    }
+
+    Ok(games)
+}
+
+fn run<F>(games: &[Game], game_score: F) -> u64
+    where F: FnMut(&Game) -> u64
+{
+    games.iter().map(game_score).sum()
+}
+
+fn part1(game: &Game) -> u64 {
+    let cubes = Cubes { red: 12, green: 13, blue: 14 };
+    if game.possible(cubes) { game.id } else { 0 }
+}
+
+fn part2(game: &Game) -> u64 {
+    game.min_cubes().power()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        read_games(input.as_bytes())
+    }
+
+    fn part1(games: &Self::Input) -> Result<u64, aoc::error::Error> {
+        Ok(run(games, part1))
+    }
+
+    fn part2(games: &Self::Input) -> Result<u64, aoc::error::Error> {
+        Ok(run(games, part2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc!("
+        Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+    ");
+
+    #[test]
+    fn part1_example() {
+        let games = read_games(Cursor::new(EXAMPLE)).unwrap();
+        assert_eq!(run(&games, part1), 8);
+    }
+
+    #[test]
+    fn part2_example() {
+        let games = read_games(Cursor::new(EXAMPLE)).unwrap();
+        assert_eq!(run(&games, part2), 2286);
+    }
+}
@@ -1,9 +1,13 @@
+mod solution;
+
 use std::io::{BufReader, Read};
 
 use itertools::Itertools;
 
 use aoc::grid::{Grid, read_grid_ascii};
 
+pub use solution::Day03;
+
 const BLANK: u8 = b'.';
 const GEAR: u8 = b'*';
 
@@ -38,18 +42,19 @@ pub fn maybe_gear(c: u8) -> bool {
 
 pub type Schematic = Grid<u8>;
 
-pub fn frame(col_span: ColSpan) -> impl Iterator<Item=(usize, usize)> {
-    let row = col_span.row;
-    let (top, bottom, left, right) = (row - 1, row + 1, col_span.start - 1, col_span.end);
-    let horiz = [top, bottom].into_iter().cartesian_product(left..=right);
-    let vert = [(row, left), (row, right)];
-    horiz.chain(vert)
+/// Cells surrounding the span
+pub fn frame(grid: &Schematic, col_span: ColSpan) -> impl Iterator<Item=(usize, usize)> + '_ {
+    let ColSpan { row, start, end } = col_span;
+    (start..end)
+        .flat_map(move |c| grid.neighbours8((row, c)))
+        .filter(move |&(r, c)| r != row || !(start..end).contains(&c))
+        .unique()
 }
 
 pub fn find_in_frame<F>(grid: &Schematic, mut predicate: F, col_span: ColSpan) -> Option<(usize, usize)>
     where F: FnMut(u8) -> bool
 {
-    frame(col_span).find(|&(r, c)| predicate(grid[r][c]))
+    frame(grid, col_span).find(|&(r, c)| predicate(grid[r][c]))
 }
 
 pub fn read_schematic<R: Read>(input: R) -> Result<Schematic, aoc::error::Error> {
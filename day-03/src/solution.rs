@@ -0,0 +1,105 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::str;
+
+use aoc::solution::Solution;
+
+use crate::{number_spans, maybe_gear, is_symbol, Schematic, ColSpan, find_in_frame, read_schematic};
+
+pub fn run<F>(schematic: &Schematic, mut score: F) -> Result<u64, aoc::error::Error>
+    where F: FnMut(&Schematic, u64, ColSpan) -> u64
+{
+    let mut total = 0;
+    for (r, row) in schematic.iter_rows().enumerate() {
+        for col_span in number_spans(r, row.iter().enumerate()) {
+            let digits = str::from_utf8(&row[col_span.start..col_span.end])?;
+            total += score(schematic, digits.parse()?, col_span);
+        }
+    }
+
+    Ok(total)
+}
+
+
+pub fn part1_fn() -> impl FnMut(&Schematic, u64, ColSpan) -> u64 {
+    |schematic, number, col_span| {
+        find_in_frame(schematic, is_symbol, col_span)
+            .map(|_| number)
+            .unwrap_or(0)
+    }
+}
+
+
+pub fn part2_fn() -> impl FnMut(&Schematic, u64, ColSpan) -> u64 {
+    let mut numbers_by_gear_pos = HashMap::new();
+
+    move |schematic, number, col_span| {
+        let gear_pos = find_in_frame(schematic, maybe_gear, col_span);
+        if let Some((gr, gc)) = gear_pos {
+            match numbers_by_gear_pos.entry((gr, gc)) {
+                Entry::Occupied(entry) => { return entry.remove() * number; }
+                Entry::Vacant(entry) => { entry.insert(number); }
+            };
+        }
+
+        0
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        read_schematic(input.as_bytes())
+    }
+
+    fn part1(schematic: &Self::Input) -> Result<u64, aoc::error::Error> {
+        run(schematic, part1_fn())
+    }
+
+    fn part2(schematic: &Self::Input) -> Result<u64, aoc::error::Error> {
+        run(schematic, part2_fn())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {"
+        467..114..
+        ...*......
+        ..35..633.
+        ......#...
+        617*......
+        .....+.58.
+        ..592.....
+        ......755.
+        ...$.*....
+        .664.598..
+    "};
+
+    #[test]
+    fn part1_example() {
+        let r = Cursor::new(EXAMPLE);
+        let schematic = read_schematic(r).unwrap();
+        let v = run(&schematic, part1_fn()).unwrap();
+        assert_eq!(v, 4361);
+    }
+
+    #[test]
+    fn part2_example() {
+        let r = Cursor::new(EXAMPLE);
+        let schematic = read_schematic(r).unwrap();
+        let v = run(&schematic, part2_fn()).unwrap();
+        assert_eq!(v, 467835);
+    }
+}
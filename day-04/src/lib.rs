@@ -1,3 +1,5 @@
+mod solution;
+
 use std::collections::HashSet;
 use std::io::{BufRead, BufReader, Read};
 use std::str::FromStr;
@@ -7,12 +9,15 @@ use itertools::Itertools;
 use aoc::error::aoc_err;
 use aoc::parse::{parse_lines, parse_spaced};
 
+pub use solution::Day04;
+
 pub type Cards = Vec<Card>;
 
 pub fn read_cards<R: Read>(input: R) -> Result<Cards, aoc::error::Error> {
     BufReader::new(input).lines().process_results(|lines| parse_lines(lines))?
 }
 
+#[derive(Debug, Clone)]
 pub struct Card {
     pub win_count: u32,
     pub copies: u32,
@@ -29,7 +34,7 @@ impl FromStr for Card {
         let win_count: u32 = have.intersection(&winning)
             .count()
             .try_into()
-            .expect("Win count too large");
+            .map_err(|_| aoc_err("Win count too large"))?;
 
         Ok(Card { win_count, copies: 1 })
     }
@@ -0,0 +1,72 @@
+use aoc::solution::Solution;
+use crate::{Cards, read_cards};
+
+fn part1(cards: Cards) -> u64 {
+    cards.iter()
+        .filter(|card| card.win_count != 0)
+        .map(|card| 2_u64.pow(card.win_count - 1))
+        .sum()
+}
+
+fn part2(mut cards: Cards) -> u64 {
+    (0..cards.len())
+        .map(|i| {
+            let (copies, win_count) = (cards[i].copies, cards[i].win_count);
+            let cards_after = &mut cards[(i + 1)..][..win_count as usize];
+            cards_after.iter_mut().for_each(|c| c.copies += copies);
+            copies as u64
+        })
+        .sum()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Cards;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        read_cards(input.as_bytes())
+    }
+
+    fn part1(cards: &Self::Input) -> Result<u64, aoc::error::Error> {
+        Ok(part1(cards.clone()))
+    }
+
+    fn part2(cards: &Self::Input) -> Result<u64, aoc::error::Error> {
+        Ok(part2(cards.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {"
+        Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+    "};
+
+    #[test]
+    fn part1_example() {
+        let cards = read_cards(Cursor::new(EXAMPLE)).unwrap();
+        let total = part1(cards);
+        assert_eq!(total, 13);
+    }
+
+    #[test]
+    fn part2_example() {
+        let cards = read_cards(Cursor::new(EXAMPLE)).unwrap();
+        let total = part2(cards);
+        assert_eq!(total, 30);
+    }
+}
@@ -1,46 +1,51 @@
-use std::io::{BufRead, BufReader, Read};
+use std::io::{BufReader, Read};
 
 use itertools::Itertools;
 
-use aoc::error::aoc_err;
-use aoc::parse::{parse_spaced_vec, some_ok_or};
+use aoc::error::{aoc_err, Location};
+use aoc::parse::{parse_spaced_vec, AtLine, Section, Sections};
+use aoc::parse::combinators::{header, parse_all, spaced};
+use aoc::range::Offset;
 
-use crate::{SeedMap, SeedMapEntry};
+use crate::{Range, SeedMap};
 
-fn parse_seed_entry(line: &str) -> Result<SeedMapEntry, aoc::error::Error> {
+fn parse_seed_entry(line: &str) -> Result<(Range, Offset<u64>), aoc::error::Error> {
     if let &[dest, src, len] = parse_spaced_vec::<u64>(line)?.as_slice() {
-        Ok(SeedMapEntry::new(src, dest, len))
+        Ok((Range::try_from_start_len(src, len)?, Offset::between(src, dest)))
     } else {
         Err(aoc_err("Incorrect format: expected 3 integers"))
     }
 }
 
-pub fn parse_seed_map(mut lines: impl Iterator<Item=String>) -> Result<SeedMap, aoc::error::Error> {
-    let line = lines.next().ok_or_else(|| aoc_err(""))?;
-    let name = line.strip_suffix(" map:").ok_or_else(|| aoc_err(""))?.to_string();
+/// Parses a map section: its name, then an entry per line
+pub fn parse_seed_map(section: &Section) -> Result<SeedMap, aoc::error::Error> {
+    let name = section.header().strip_suffix(" map:")
+        .ok_or_else(|| aoc_err("Expected '<name> map:'"))
+        .map_err(|e| section.locate(e))?
+        .to_string();
 
-    let entries: Vec<_> = lines
-        .take_while(|line| !line.is_empty())
-        .map(|line| parse_seed_entry(&line))
+    let translation = section.body().iter()
+        .enumerate()
+        .map(|(i, line)| parse_seed_entry(line).at_line(section.line_no(i + 1), line))
         .try_collect()?;
 
-    Ok(SeedMap::new(name, entries))
+    Ok(SeedMap::new(name, translation))
 }
 
 pub fn read_seed_maps<R: Read>(input: R) -> Result<(Vec<u64>, Vec<SeedMap>), aoc::error::Error> {
-    let mut lines = BufReader::new(input).lines();
-
-    let first = some_ok_or(lines.next(), "Empty file")?;
-    let seeds = first.strip_prefix("seeds: ").ok_or_else(|| aoc_err("Expected seeds line"))?;
-    let seed_numbers = parse_spaced_vec(seeds)?;
-    let _blank = some_ok_or(lines.next(), "Expected blank line")?;
-
-    let maps: Vec<_> = lines
-        .map(|line_res| line_res.unwrap())
-        .group_by(|line| line.is_empty())
-        .into_iter()
-        .filter(|(empty, _)| !empty)
-        .map(|(_, group)| parse_seed_map(group.into_iter()))
+    let mut sections = Sections::new(BufReader::new(input));
+
+    let seeds = sections.expect("seeds")?;
+    let (key, seed_numbers) = parse_all(seeds.header(), header(spaced)).map_err(|e| seeds.locate(e))?;
+    if key != "seeds" {
+        return Err(aoc_err(format!("Expected 'seeds', not '{key}'")).at(Location::of(seeds.line_no(0), seeds.header(), key)));
+    }
+    if let Some(line) = seeds.body().first() {
+        return Err(aoc_err("Expected blank line").at_line(seeds.line_no(1), line));
+    }
+
+    let maps = sections
+        .map(|section| parse_seed_map(&section?))
         .try_collect()?;
 
     Ok((seed_numbers, maps))
@@ -0,0 +1,139 @@
+use itertools::Itertools;
+use aoc::error::{no_solution, Error};
+use aoc::solution::Solution;
+
+use crate::{parse, Range, RangeSet, SeedMap, Translation};
+
+// All the maps collapsed into one, from seed to location
+fn seed_to_location(maps: &[SeedMap]) -> Translation {
+    maps.iter().fold(Translation::new(), |seed_to, map| seed_to.then(map.translation()))
+}
+
+fn part1(seed_numbers: &[u64], maps: &[SeedMap]) -> Result<u64, Error> {
+    let almanac = seed_to_location(maps);
+    seed_numbers.iter()
+        .map(|&seed| almanac.translate(seed))
+        .min()
+        .ok_or_else(|| no_solution("No seeds"))
+}
+
+fn part2(seed_numbers: &[u64], maps: &[SeedMap]) -> Result<u64, Error> {
+    let seeds: RangeSet = seed_numbers.iter()
+        .tuples::<(_, _)>()
+        .map(|(&start, &len)| Range::try_from_start_len(start, len))
+        .try_collect()?;
+
+    seed_to_location(maps)
+        .translate_ranges(&seeds)
+        .start()
+        .ok_or_else(|| no_solution("No seeds"))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (Vec<u64>, Vec<SeedMap>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        parse::read_seed_maps(input.as_bytes())
+    }
+
+    fn part1((seeds, maps): &Self::Input) -> Result<u64, aoc::error::Error> {
+        part1(seeds, maps)
+    }
+
+    fn part2((seeds, maps): &Self::Input) -> Result<u64, aoc::error::Error> {
+        part2(seeds, maps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {"
+        seeds: 79 14 55 13
+
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+
+        water-to-light map:
+        88 18 7
+        18 25 70
+
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+
+        humidity-to-location map:
+        60 56 37
+        56 93 4
+    "};
+
+    #[test]
+    fn part1_example() {
+        let f = Cursor::new(EXAMPLE);
+        let (seeds, maps) = parse::read_seed_maps(f).unwrap();
+        let total = part1(&seeds, &maps).unwrap();
+        assert_eq!(total, 35);
+    }
+
+    #[test]
+    fn part2_example() {
+        let f = Cursor::new(EXAMPLE);
+        let (seeds, maps) = parse::read_seed_maps(f).unwrap();
+        let total = part2(&seeds, &maps).unwrap();
+        assert_eq!(total, 46);
+    }
+
+    #[test]
+    fn bad_entry_location() {
+        let input = EXAMPLE.replace("52 50 48", "52 5x 48");
+        let error = parse::read_seed_maps(Cursor::new(input)).unwrap_err();
+        let location = error.location().unwrap();
+        assert_eq!((location.line, location.column()), (5, 4));
+        assert_eq!(location.excerpt, "52 5x 48");
+    }
+
+    #[test]
+    fn bad_seeds_location() {
+        let input = format!("\n\n{}", EXAMPLE.replace("seeds:", "sedes:"));
+        let error = parse::read_seed_maps(Cursor::new(input)).unwrap_err();
+        let location = error.location().unwrap();
+        assert_eq!((location.line, location.column()), (3, 1));
+    }
+
+    #[test]
+    fn overflowing_entry() {
+        let input = EXAMPLE.replace("52 50 48", "1 18446744073709551615 5");
+        let error = parse::read_seed_maps(Cursor::new(input)).unwrap_err();
+        assert_eq!(error.location().unwrap().line, 5);
+
+        let input = EXAMPLE.replace("seeds: 79 14", "seeds: 18446744073709551615 14");
+        let (seeds, maps) = parse::read_seed_maps(Cursor::new(input)).unwrap();
+        assert!(part2(&seeds, &maps).is_err());
+    }
+}
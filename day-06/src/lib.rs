@@ -0,0 +1,136 @@
+use std::fmt::Debug;
+use std::num::ParseIntError;
+
+use indoc::indoc;
+use itertools::Itertools;
+use aoc::error::aoc_err;
+use aoc::parse::{parse_spaced_vec, AtLine};
+use aoc::solution::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Race {
+    time: u64,
+    distance: u64
+}
+
+fn quadratic_roots(b: f64, c: f64) -> Option<(f64, f64)> {
+    let disc_sqrt = (b * b - 4.0 * c).sqrt();
+    if disc_sqrt.is_nan() {
+        return None;
+    }
+
+    let r1 = (-b - disc_sqrt) / 2.0;
+    let r2 = (-b + disc_sqrt) / 2.0;
+    Some((r1, r2))
+}
+
+fn win_count(race: Race) -> u64 {
+    let t = race.time as f64;
+    let k = race.distance as f64;
+    // Solve for integer h, hold time:
+    // distance = h(t - h) > k => h^2 - ht + k < 0
+    if let Some((h1, h2)) = quadratic_roots(-t, k) {
+        (h2.ceil() as u64 - 1) - (h1.floor() as u64 + 1) + 1
+    } else {
+        // No real roots => distance was impossible => no wins
+        0
+    }
+}
+
+fn squashed(line: &str) -> Result<u64, ParseIntError> {
+    line.chars().filter(|&c| !c.is_whitespace()).collect::<String>().parse()
+}
+
+fn parse_races(s: &str, squash_space: bool) -> Result<Vec<Race>, aoc::error::Error> {
+    let lines = s.split_terminator('\n')
+        .filter(|line| !line.is_empty())
+        .collect_vec();
+
+    let [times, distances] = lines.as_slice() else { return Err(aoc_err("Incorrect line count")) };
+    let times = times.strip_prefix("Time:").ok_or_else(|| aoc_err("No Time row"))?;
+    let distances = distances.strip_prefix("Distance:").ok_or_else(|| aoc_err("No Distances row"))?;
+
+    let result = if squash_space {
+        vec![Race { time: squashed(times).at_line(1, lines[0])?, distance: squashed(distances).at_line(2, lines[1])? }]
+    } else {
+        let times: Vec<u64> = parse_spaced_vec(times).at_line(1, lines[0])?;
+        let distances: Vec<u64> = parse_spaced_vec(distances).at_line(2, lines[1])?;
+        if times.len() != distances.len() {
+            let message = format!("{} distances for {} times", distances.len(), times.len());
+            return Err(aoc_err(message).at_line(2, lines[1]));
+        }
+        times.into_iter().zip(distances)
+            .map(|(time, distance)| Race { time, distance })
+            .collect_vec()
+    };
+
+    Ok(result)
+}
+
+fn part1(s: &str) -> Result<u64, aoc::error::Error> {
+    let races = parse_races(s, false)?;
+    Ok(races.into_iter().map(win_count).product())
+}
+
+fn part2(s: &str) -> Result<u64, aoc::error::Error> {
+    // Squashed into one race
+    let races = parse_races(s, true)?;
+    Ok(win_count(races[0]))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const INPUT: Option<&'static str> = Some(indoc!{r"
+        Time:        56     71     79     99
+        Distance:   334   1135   1350   2430
+    "});
+
+    type Input = String;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Result<u64, aoc::error::Error> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64, aoc::error::Error> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = indoc! {"
+        Time:      7  15   30
+        Distance:  9  40  200
+    "};
+
+    #[test]
+    fn part1_example() {
+        let total = part1(EXAMPLE).unwrap();
+        assert_eq!(total, 288);
+    }
+
+    #[test]
+    fn part2_example() {
+        let total = part2(EXAMPLE).unwrap();
+        assert_eq!(total, 71503);
+    }
+
+    #[test]
+    fn mismatched_rows() {
+        for input in ["Time: 7\nDistance:\n", "Time: 7 15\nDistance: 9\n"] {
+            let e = part1(input).unwrap_err();
+            assert_eq!(e.location().unwrap().line, 2, "{input}");
+        }
+        assert_eq!(part2("Time: 7\nDistance:\n").unwrap_err().location().unwrap().line, 2);
+    }
+}
@@ -1,14 +1,19 @@
 #![allow(clippy::redundant_field_names)]
 
+mod solution;
+
 use std::io::{BufRead, BufReader, Read};
 use std::str;
 use std::str::FromStr;
 use itertools::Itertools;
-use aoc::error::aoc_err;
 use aoc::CollectArray;
+use aoc::error::{aoc_err, Location};
+use aoc::parse::AtLine;
+use aoc_derive::FromFormat;
+
+pub use solution::Day07;
 
 const CARDS: [char; 14] = ['*', '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
-const JOKER_STR: &str = "*";
 const JOKER_ORD: usize = 0;
 const HAND_SIZE: usize = 5;
 
@@ -61,9 +66,7 @@ pub fn chosen_joker_hand(cards: &HandArray<Card>) -> HandArray<Card> {
         .unwrap() + 1;
 
     let joker = Card { ord: mode_ord_no_joker };
-    cards.iter()
-        .map(|&c| if c.ord == JOKER_ORD { joker } else { c })
-        .collect_array()
+    cards.map(|c| if c.ord == JOKER_ORD { joker } else { c })
 }
 
 fn hand_type(cards: &HandArray<Card>) -> HandType {
@@ -98,48 +101,50 @@ impl Hand {
             cards: cards
         }
     }
+
+    /// The same hand with its jacks played as jokers
+    pub fn with_jokers(self) -> Self {
+        let jack = Card::try_from('J').expect("Jack is a card");
+        Self::new(self.cards.map(|c| if c == jack { Card { ord: JOKER_ORD } } else { c }))
+    }
 }
 
 impl FromStr for Hand {
-    type Err = String;
+    type Err = aoc::error::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() != HAND_SIZE {
-            return Err(s.to_owned());
+        let cards = s.char_indices()
+            .map(|(i, c)| Card::try_from(c)
+                .map_err(|_| aoc_err(format!("Unknown card '{c}'")).at(Location::new(1, s, i..i + c.len_utf8()))))
+            .process_results(|cards| cards.try_collect_array());
+
+        match cards {
+            Ok(Ok(cards)) => Ok(Self::new(cards)),
+            // A bad card is already located, a wrong number of them is the whole hand
+            Ok(Err(e)) | Err(e) => Err(e.at(Location::new(1, s, 0..s.len()))),
         }
-
-        let cards = s.chars()
-            .map(Card::try_from)
-            .process_results(|cards| cards.collect_array())
-            .map_err(|_| s.to_owned())?;
-
-        Ok(Self::new(cards))
     }
 }
 
+#[derive(FromFormat)]
+#[aoc(format = "{hand} {bid}")]
+struct HandBid {
+    hand: String,
+    bid: u32,
+}
+
 fn parse_line(line: &str, use_jokers: bool) -> Result<(Hand, u32), aoc::error::Error> {
-    let split = line.split_ascii_whitespace().collect_vec();
-    let &[hand, bid] = split.as_slice() else {
-        return Err(aoc_err("Bad line"));
-    };
-
-    let hand: Hand = if use_jokers {
-        hand.replace('J', JOKER_STR).parse()?
-    } else {
-        hand.parse()?
-    };
-
-    let bid: u32 = bid.parse()?;
-    Ok((hand, bid))
+    let HandBid { hand, bid } = line.parse()?;
+    let hand: Hand = hand.parse()?;
+    Ok((if use_jokers { hand.with_jokers() } else { hand }, bid))
 }
 
 pub fn read_hand_bids<R: Read>(input: R, use_jokers: bool) -> Result<Vec<(Hand, u32)>, aoc::error::Error> {
     let lines = BufReader::new(input).lines();
-    lines
-        .process_results(|lines| {
-            let hand_bids: Vec<(Hand, u32)> = lines.into_iter()
-                .map(|line: String| parse_line(&line, use_jokers))
-                .try_collect()?;
-            Ok(hand_bids)
-        })?
+    lines.enumerate()
+        .map(|(i, line)| {
+            let line = line?;
+            parse_line(&line, use_jokers).at_line(i + 1, &line)
+        })
+        .try_collect()
 }
@@ -0,0 +1,83 @@
+use std::io::Read;
+use aoc::solution::Solution;
+use crate::read_hand_bids;
+
+fn run<R: Read>(input: R, use_jokers: bool) -> Result<u32, aoc::error::Error> {
+    let mut hand_bids = read_hand_bids(input, use_jokers)?;
+    hand_bids.sort_by_key(|&(hand, _)| hand);
+
+    let total = hand_bids.iter().enumerate()
+        .map(|(i, (_, bid))| (i + 1) as u32 * bid)
+        .sum();
+
+    Ok(total)
+}
+
+fn part1<R: Read>(input: R) -> Result<u32, aoc::error::Error> {
+    run(input, false)
+}
+
+fn part2<R: Read>(input: R) -> Result<u32, aoc::error::Error> {
+    run(input, true)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = String;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Result<u32, aoc::error::Error> {
+        part1(input.as_bytes())
+    }
+
+    fn part2(input: &Self::Input) -> Result<u32, aoc::error::Error> {
+        part2(input.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {"
+        32T3K 765
+        T55J5 684
+        KK677 28
+        KTJJT 220
+        QQQJA 483
+    "};
+
+    #[test]
+    fn part1_example() {
+        let total = part1(Cursor::new(EXAMPLE)).unwrap();
+        assert_eq!(total, 6440);
+    }
+
+    #[test]
+    fn part2_example() {
+        let total = part2(Cursor::new(EXAMPLE)).unwrap();
+        assert_eq!(total, 5905);
+    }
+
+    #[test]
+    fn bad_hands() {
+        let e = "KK6X7".parse::<crate::Hand>().unwrap_err();
+        assert_eq!(e.location().unwrap().span, 3..4);
+        let e = "KK67".parse::<crate::Hand>().unwrap_err();
+        assert!(e.to_string().starts_with("Expected 5 items, found 4"));
+
+        let e = part1(Cursor::new("32T3K 765\nT55J5 684\nKK6X7 28\n")).unwrap_err();
+        assert_eq!(e.location().unwrap().line, 3);
+        assert_eq!(e.location().unwrap().span, 3..4);
+    }
+}
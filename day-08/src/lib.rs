@@ -1,3 +1,5 @@
+mod solution;
+
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Formatter};
 use std::io::{BufRead, BufReader, Read};
@@ -5,9 +7,15 @@ use std::{iter, str};
 use std::ops::Index;
 use std::str::FromStr;
 use itertools::Itertools;
+use nom::character::complete::char;
+use nom::combinator::map_res;
+use nom::sequence::{delimited, separated_pair};
 use aoc::CollectArray;
 use aoc::error::aoc_err;
-use aoc::parse::some_ok_or;
+use aoc::parse::{some_ok_or, AtLine};
+use aoc::parse::combinators::{list, parse_all, trim, word};
+
+pub use solution::Day08;
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Direction { Left = 0, Right = 1 }
@@ -75,7 +83,7 @@ impl Debug for Node {
 }
 
 impl FromStr for Node {
-    type Err = String;
+    type Err = aoc::error::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let x: [u8; N] = s.bytes().pad_using(N, |_| b'_').try_collect_array()?;
@@ -99,19 +107,20 @@ impl Graph {
         let mut dir_iter = self.directions.iter().copied().enumerate().cycle();
 
         iter::from_fn(move || {
-            let (dir_ix, dir) = dir_iter.next().expect("Iterator should be inifinite");
+            // Only exhausted if there are no directions
+            let (dir_ix, dir) = dir_iter.next()?;
             if !visited_node_dirs.insert((dir_ix, node)) {
                 if cfg!(debug_assertions) {
-                    eprintln!("{start_node:?}: Cycle at {node:?}, dir_ix {dir_ix} {dir:?}");
+                    aoc::diag!("{start_node:?}: Cycle at {node:?}, dir_ix {dir_ix} {dir:?}");
                 }
                 // Exhausted
                 return None;
             }
 
-            let (left, right) = self.edges[&node];
+            let &(left, right) = self.edges.get(&node)?;
             if left == right && left == node {
                 if cfg!(debug_assertions) {
-                    eprintln!("Left & right self-loops at node {node:?}");
+                    aoc::diag!("Left & right self-loops at node {node:?}");
                 }
                 // Exhausted
                 return None;
@@ -123,14 +132,11 @@ impl Graph {
     }
 }
 
-fn parse_line(line: String) -> Result<(Node, (Node, Node)), aoc::error::Error> {
-    let (source, _, left, right) = line
-        .split_ascii_whitespace()
-        .collect_tuple()
-        .ok_or("Line is not <source> = (<left>, <right>)")?;
-    let source: Node = source.parse()?;
-    let left: Node = left.trim_matches(&['(', ',']).parse()?;
-    let right: Node = right.trim_matches(&[')']).parse()?;
+/// Parses `<source> = (<left>, <right>)`
+fn parse_line(line: &str) -> Result<(Node, (Node, Node)), aoc::error::Error> {
+    let node = || map_res(word, Node::from_str);
+    let targets = map_res(delimited(char('('), list(node()), char(')')), <[Node; 2]>::try_from);
+    let (source, [left, right]) = parse_all(line, separated_pair(node(), trim(char('=')), targets))?;
     Ok((source, (left, right)))
 }
 
@@ -150,9 +156,12 @@ pub fn read_graph<R: Read>(input: R) -> Result<Graph, aoc::error::Error> {
         .map(Direction::try_from)
         .try_collect()?;
     let _blank = some_ok_or(lines.next(), "Expected blank line")?;
-    let node_to_adj_pair = lines.process_results(|lines| {
-        lines.map(parse_line).try_collect()
-    })??;
+    let node_to_adj_pair = lines.enumerate()
+        .map(|(i, line)| {
+            let line = line?;
+            parse_line(&line).at_line(i + 3, &line)
+        })
+        .try_collect()?;
 
     if !verify(&node_to_adj_pair) {
         return Err(aoc_err("Edge refers to missing node"))
@@ -0,0 +1,131 @@
+use itertools::Itertools;
+
+use aoc::error::aoc_err;
+use aoc::solution::Solution;
+
+use crate::{Graph, Node, read_graph};
+
+pub fn run<P>(graph: &Graph, mut is_start_node: P) -> Result<usize, aoc::error::Error>
+    where
+        P: FnMut(Node) -> bool
+{
+    let start_nodes = graph.nodes()
+        .filter(|&n| is_start_node(n))
+        .sorted()
+        .collect_vec();
+
+    if start_nodes.is_empty() {
+        return Err(aoc_err("No start nodes"));
+    }
+
+    let hops_to_z: Vec<usize> = start_nodes.into_iter()
+        .flat_map(|node| hops_to_z(graph, node))
+        .collect_vec();
+
+    let lcm = hops_to_z
+        .into_iter()
+        .reduce(num::integer::lcm)
+        .ok_or("No hops to Z")?;
+
+    Ok(lcm)
+}
+
+fn hops_to_z(graph: &Graph, start_node: Node) -> Vec<usize> {
+    graph.iter_at(start_node)
+        .enumerate()
+        .filter(|&(_, node)| node.ends_with(b'Z'))
+        .map(|(hop_ix, _)| hop_ix + 1)
+        .collect_vec()
+}
+
+fn part1_fn() -> Result<impl FnMut(Node) -> bool, aoc::error::Error> {
+    let aaa: Node = "AAA".parse()?;
+    Ok(move |node: Node| node == aaa)
+}
+
+fn part2_fn() -> Result<impl FnMut(Node) -> bool, aoc::error::Error> {
+    Ok(|node: Node| node.ends_with(b'A'))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Graph;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        read_graph(input.as_bytes())
+    }
+
+    fn part1(graph: &Self::Input) -> Result<usize, aoc::error::Error> {
+        run(graph, part1_fn()?)
+    }
+
+    fn part2(graph: &Self::Input) -> Result<usize, aoc::error::Error> {
+        run(graph, part2_fn()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use indoc::indoc;
+
+    const EXAMPLE1_1: &str = indoc! {"
+        RL
+
+        AAA = (BBB, CCC)
+        BBB = (DDD, EEE)
+        CCC = (ZZZ, GGG)
+        DDD = (DDD, DDD)
+        EEE = (EEE, EEE)
+        GGG = (GGG, GGG)
+        ZZZ = (ZZZ, ZZZ)
+    "};
+
+    const EXAMPLE1_2: &str = indoc! {"
+        LLR
+
+        AAA = (BBB, BBB)
+        BBB = (AAA, ZZZ)
+        ZZZ = (ZZZ, ZZZ)
+    "};
+
+    const EXAMPLE2: &str = indoc! {"
+        LR
+
+        11A = (11B, XXX)
+        11B = (XXX, 11Z)
+        11Z = (11B, XXX)
+        22A = (22B, XXX)
+        22B = (22C, 22C)
+        22C = (22Z, 22Z)
+        22Z = (22B, 22B)
+        XXX = (XXX, XXX)
+    "};
+
+    #[test]
+    fn part1_example1() {
+        let part1 = part1_fn().unwrap();
+        let total = run(&read_graph(Cursor::new(EXAMPLE1_1)).unwrap(), part1).unwrap();
+        assert_eq!(total, 2);
+    }
+
+    #[test]
+    fn part1_example2() {
+        let part1 = part1_fn().unwrap();
+        let total = run(&read_graph(Cursor::new(EXAMPLE1_2)).unwrap(), part1).unwrap();
+        assert_eq!(total, 6);
+    }
+
+    #[test]
+    fn part2_example() {
+        let part2 = part2_fn().unwrap();
+        let total = run(&read_graph(Cursor::new(EXAMPLE2)).unwrap(), part2).unwrap();
+        assert_eq!(total, 6);
+    }
+}
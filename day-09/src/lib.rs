@@ -0,0 +1,96 @@
+use std::io::{BufRead, BufReader, Read};
+
+use itertools::Itertools;
+use aoc::parse::{parse_spaced, AtLine};
+use aoc::solution::Solution;
+
+fn extrapolate(values: &[i64]) -> i64 {
+    if values.is_empty() {
+        return 0;
+    }
+
+    let mut last_row = values.to_owned();
+    let mut diagonal = Vec::new();
+    while last_row.iter().any(|&x| x != 0) {
+        diagonal.push(*last_row.last().unwrap());
+        last_row = last_row.iter().copied()
+            .tuple_windows::<(_, _)>()
+            .map(|(a, b)| b - a)
+            .collect_vec();
+    }
+
+    diagonal.into_iter().sum()
+}
+
+fn read_sequences<R: Read>(input: R) -> Result<Vec<Vec<i64>>, aoc::error::Error> {
+    let lines = BufReader::new(input).lines();
+    let mut sequences = Vec::new();
+    for (i, line) in lines.enumerate() {
+        let line = line?;
+        sequences.push(parse_spaced(&line).at_line(i + 1, &line)?);
+    }
+
+    Ok(sequences)
+}
+
+fn run(sequences: &[Vec<i64>], backwards: bool) -> i64 {
+    let mut total = 0;
+    for nums in sequences {
+        let mut nums = nums.clone();
+        if backwards {
+            nums.reverse();
+        }
+        total += extrapolate(&nums);
+    }
+
+    total
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i64>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        read_sequences(input.as_bytes())
+    }
+
+    fn part1(sequences: &Self::Input) -> Result<i64, aoc::error::Error> {
+        Ok(run(sequences, false))
+    }
+
+    fn part2(sequences: &Self::Input) -> Result<i64, aoc::error::Error> {
+        Ok(run(sequences, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {"
+        0 3 6 9 12 15
+        1 3 6 10 15 21
+        10 13 16 21 30 45
+    "};
+
+    #[test]
+    fn part1_example() {
+        let sequences = read_sequences(Cursor::new(EXAMPLE)).unwrap();
+        let total = run(&sequences, false);
+        assert_eq!(total, 114);
+    }
+
+    #[test]
+    fn part2_example() {
+        let sequences = read_sequences(Cursor::new(EXAMPLE)).unwrap();
+        let total = run(&sequences, true);
+        assert_eq!(total, 2);
+    }
+}
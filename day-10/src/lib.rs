@@ -1,16 +1,20 @@
+mod solution;
+
 use std::fmt;
 use std::io::{BufReader, Read};
 
 use aoc::grid::{Way, Ways, Grid, read_grid_with_transform};
-use aoc::infallible;
+use aoc::error::{aoc_err, no_solution, Error};
+
+pub use solution::Day10;
 
 const BLANK: u8 = b'.';
 const START: u8 = b'S';
 
 pub type Maze = Grid<Ways>;
 
-pub fn start(maze: &Maze) -> (usize, usize) {
-    maze.position(|&tile| tile == Ways::all()).expect("Start missing")
+pub fn start(maze: &Maze) -> Result<(usize, usize), Error> {
+    maze.position(|&tile| tile == Ways::all()).ok_or_else(|| no_solution("Start missing"))
 }
 
 pub fn ways_available(maze: &Maze, pos: (usize, usize)) -> Ways {
@@ -26,8 +30,8 @@ pub fn ways_available(maze: &Maze, pos: (usize, usize)) -> Ways {
     if ways.len() < 2 { Ways::empty() } else { ways }
 }
 
-fn tile_to_ways(c: u8) -> Ways {
-    match c {
+fn tile_to_ways(c: u8) -> Result<Ways, Error> {
+    Ok(match c {
         b'|' => Way::Up | Way::Down,
         b'-' => Way::Left | Way::Right,
         b'J' => Way::Up | Way::Left,
@@ -36,12 +40,12 @@ fn tile_to_ways(c: u8) -> Ways {
         b'F' => Way::Down | Way::Right,
         START => Ways::all(),
         BLANK => Ways::empty(),
-        _ => panic!("Invalid tile: {c}")
-    }
+        _ => return Err(aoc_err(format!("Invalid tile: {}", c.escape_ascii())))
+    })
 }
 
 #[allow(dead_code)]
-fn ways_to_tile(ways: Ways) -> u8 {
+fn ways_to_tile(ways: Ways) -> Option<u8> {
     [
         (Way::Up | Way::Down, b'|'),
         (Way::Left | Way::Right, b'-'),
@@ -53,8 +57,7 @@ fn ways_to_tile(ways: Ways) -> u8 {
         (Ways::empty(), BLANK),
     ].into_iter()
         .find(|&(w, _pipe)| ways == w)
-        .unwrap_or_else(|| panic!("Invalid ways: {ways:?}"))
-        .1
+        .map(|(_, pipe)| pipe)
 }
 
 fn ways_to_graphic(ways: Ways) -> char {
@@ -69,8 +72,7 @@ fn ways_to_graphic(ways: Ways) -> char {
         (Ways::empty(), ' '),
     ].into_iter()
         .find(|&(w, _pipe)| ways == w)
-        .unwrap_or_else(|| panic!("Invalid ways: {ways:?}"))
-        .1
+        .map_or('?', |(_, pipe)| pipe)
 }
 
 fn maze_fmt(maze: &Maze, f: &mut fmt::Formatter) -> fmt::Result {
@@ -83,9 +85,9 @@ fn maze_fmt(maze: &Maze, f: &mut fmt::Formatter) -> fmt::Result {
     Ok(())
 }
 
-pub fn maze_pipe_loop(maze: &Maze) -> Result<Vec<(usize, usize)>, aoc::error::Error> {
+pub fn maze_pipe_loop(maze: &Maze) -> Result<Vec<(usize, usize)>, Error> {
     let mut main_loop = Vec::new();
-    let start = start(maze);
+    let start = start(maze)?;
     let mut pos = start;
     let mut prev_way: Option<Way> = None;
     loop {
@@ -100,7 +102,7 @@ pub fn maze_pipe_loop(maze: &Maze) -> Result<Vec<(usize, usize)>, aoc::error::Er
             return Err(format!("Expect one way, have {:?}", ways).into());
         }
 
-        let way = ways.into_iter().next().unwrap();
+        let way = ways.into_iter().next().ok_or_else(|| no_solution("No pipe leads from the start"))?;
         pos = way.step(pos);
         if pos == start {
             break;
@@ -112,12 +114,12 @@ pub fn maze_pipe_loop(maze: &Maze) -> Result<Vec<(usize, usize)>, aoc::error::Er
     Ok(main_loop)
 }
 
-pub fn read_maze<R: Read>(input: R) -> Result<Maze, aoc::error::Error> {
+pub fn read_maze<R: Read>(input: R) -> Result<Maze, Error> {
     let mut reader = BufReader::new(input);
     read_grid_with_transform(
         &mut reader,
         Some(Ways::empty()),
-        infallible(tile_to_ways),
+        tile_to_ways,
         maze_fmt
     )
 }
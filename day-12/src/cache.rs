@@ -34,7 +34,7 @@ impl<K: Eq + Hash, V> Cache<K, V> {
         }
     }
 
-    pub fn get<Q: ?Sized>(&self, key: &Q) -> Option<(&V, usize)> where K: Borrow<Q>, Q: Hash + Eq {
+    pub fn get<Q>(&self, key: &Q) -> Option<(&V, usize)> where K: Borrow<Q>, Q: ?Sized + Hash + Eq {
         let opt = self.inner.get(key);
         let mut stats = self.stats.get();
 
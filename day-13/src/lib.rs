@@ -0,0 +1,119 @@
+use std::io::{BufReader, Read};
+use itertools::Itertools;
+use aoc::CumulativeExt;
+use aoc::solution::Solution;
+
+use aoc::grid::Axis;
+use aoc::parse::Sections;
+
+type Grid = aoc::grid::Grid<u8>;
+
+fn solve(grid: &Grid, axis: Axis, require_smudge: bool) -> Option<usize> {
+    let diffs_required = if require_smudge { 1 } else { 0 };
+
+    (0..grid.len(axis) - 1).filter_map(|i| {
+        is_reflection(grid, axis, i, diffs_required).then_some(i + 1)
+    }).next()
+}
+
+fn read_grids<R: Read>(input: R) -> Result<Vec<Grid>, aoc::error::Error> {
+    Sections::new(BufReader::new(input))
+        .map(|section| section?.grid(None))
+        .collect()
+}
+
+fn run(grids: &[Grid], require_smudge: bool) -> usize {
+    let mut total = 0;
+    for grid in grids {
+        // eprintln!("{}", &grid);
+        total += solve(grid, Axis::Row, require_smudge)
+            .map_or_else(
+                || solve(grid, Axis::Column, require_smudge),
+                |r| Some(100 * r),
+            )
+            .unwrap_or(0);
+    }
+
+    total
+}
+
+fn is_reflection(grid: &Grid, axis: Axis, reflect_ix: usize, diffs_required: usize) -> bool {
+    let reverse = (0..=reflect_ix).rev().map(|i| grid.get(axis, i).copied());
+    let forward = (reflect_ix + 1..grid.len(axis)).map(|i| grid.get(axis, i).copied());
+    let diffs = reverse.zip(forward)
+        .map(|(line1, line2)| diff_count(line1, line2, diffs_required + 1))
+        .cumulative_sum()
+        .take_while_inclusive(|&diffs| diffs <= diffs_required)
+        .last();
+
+    // No lines to compare when reflecting at the edge
+    diffs == Some(diffs_required)
+}
+
+fn diff_count<I: Iterator<Item=u8>>(line1: I, line2: I, max_diffs: usize) -> usize {
+    itertools::zip_eq(line1, line2)
+        .filter(|&(cell1, cell2)| cell1 != cell2)
+        .take(max_diffs)
+        .count()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Grid>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        read_grids(input.as_bytes())
+    }
+
+    fn part1(grids: &Self::Input) -> Result<usize, aoc::error::Error> {
+        Ok(run(grids, false))
+    }
+
+    fn part2(grids: &Self::Input) -> Result<usize, aoc::error::Error> {
+        Ok(run(grids, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use indoc::indoc;
+
+    const EXAMPLE1: &str = indoc! {"
+        #.##..##.
+        ..#.##.#.
+        ##......#
+        ##......#
+        ..#.##.#.
+        ..##..##.
+        #.#.##.#.
+
+        #...##..#
+        #....#..#
+        ..##..###
+        #####.##.
+        #####.##.
+        ..##..###
+        #....#..#
+    "};
+
+    #[test]
+    fn part1_example() {
+        let grids = read_grids(Cursor::new(EXAMPLE1)).unwrap();
+        let answer = run(&grids, false);
+        assert_eq!(answer, 405);
+    }
+
+    #[test]
+    fn part2_example() {
+        let grids = read_grids(Cursor::new(EXAMPLE1)).unwrap();
+        let answer = run(&grids, true);
+        assert_eq!(answer, 400);
+    }
+}
@@ -0,0 +1,151 @@
+use std::io::{BufReader, Read};
+
+use itertools::Itertools;
+use aoc::cycle::nth_state;
+use aoc::solution::Solution;
+
+use aoc::grid::{Axis, read_grid_ascii, Rgb, Way};
+
+type Grid = aoc::grid::Grid<u8>;
+
+fn iter_grid(grid: &Grid, way: Way) -> impl Iterator<Item=(usize, usize)> {
+    let row_iter = 0..grid.len(Axis::Row);
+    let col_iter = 0..grid.len(Axis::Column);
+
+    let result: Box<dyn Iterator<Item=_>> = match way {
+        // Bottom-right to top-left, reverse direction
+        Way::Up | Way::Left => Box::new(
+            row_iter.rev().cartesian_product(col_iter.rev())
+        ),
+        // Top-left to bottom-right, forward direction
+        Way::Down | Way::Right => Box::new(
+            row_iter.cartesian_product(col_iter)
+        ),
+    };
+
+    result
+}
+
+fn tilt(grid: &mut Grid, way: Way) {
+    let tilt_axis = way.axis_changing();
+    let mut round_rocks_tracked = vec![0; grid.len(tilt_axis.other())];
+
+    for rc in iter_grid(grid, way) {
+        let j = if tilt_axis == Axis::Row { rc.1 } else { rc.0 };
+        match grid[rc] {
+            b'O' => {
+                // Remove but track rock
+                round_rocks_tracked[j] += 1;
+                grid[rc] = b'.';
+            },
+            b'#' => {
+                // Place tracked rocks
+                for i in 1..=round_rocks_tracked[j] {
+                    let rock = way.flipped().steps(rc, i);
+                    grid[rock] = b'O';
+                }
+
+                round_rocks_tracked[j] = 0;
+            },
+            _ => {},
+        }
+    }
+
+    aoc::frame!(grid = grid, rock_colour);
+}
+
+fn rock_colour(&tile: &u8) -> Rgb {
+    match tile {
+        b'O' => Rgb(200, 120, 40),
+        b'#' => Rgb(60, 60, 60),
+        _ => Rgb::WHITE,
+    }
+}
+
+fn calc_load(grid: &Grid, way: Way) -> usize {
+    let axis = way.axis_changing();
+
+    iter_grid(grid, way).map(|rc| {
+        let i = if axis == Axis::Row { rc.0 } else { rc.1 };
+        if grid[rc] == b'O' { grid.len(axis) - i - 1 } else { 0 }
+    }).sum()
+}
+
+fn spin(grid: &mut Grid) {
+    for way in [Way::Up, Way::Left, Way::Down, Way::Right] {
+        tilt(grid, way);
+    }
+}
+
+fn part1(mut grid: Grid) -> usize {
+    tilt(&mut grid, Way::Up);
+    calc_load(&grid, Way::Up)
+}
+
+fn part2(grid: Grid) -> usize {
+    const SPINS: usize = 1_000_000_000;
+
+    let grid = nth_state(grid, spin, SPINS);
+    calc_load(&grid, Way::Up)
+}
+
+fn read_platform<R: Read>(input: R) -> Result<Grid, aoc::error::Error> {
+    let mut reader = BufReader::new(input);
+    read_grid_ascii(&mut reader, Some(b'#'))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        read_platform(input.as_bytes())
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize, aoc::error::Error> {
+        Ok(part1(grid.clone()))
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize, aoc::error::Error> {
+        Ok(part2(grid.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {"
+        O....#....
+        O.OO#....#
+        .....##...
+        OO.#O....O
+        .O.....O#.
+        O.#..O.#.#
+        ..O..#O..O
+        .......O..
+        #....###..
+        #OO..#....
+    "};
+
+    #[test]
+    fn part1_example() {
+        let grid = read_platform(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part1(grid);
+        assert_eq!(answer, 136);
+    }
+
+    #[test]
+    fn part2_example() {
+        let grid = read_platform(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part2(grid);
+        assert_eq!(answer, 64);
+    }
+}
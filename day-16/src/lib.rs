@@ -0,0 +1,223 @@
+use std::fmt::{Display, Formatter};
+use std::io::{BufReader, Read};
+use enumset::{EnumSet, EnumSetType};
+use aoc::grid::{Grid, read_grid, Rgb, Way, Ways};
+use aoc::solution::Solution;
+
+pub type Tiles = Grid<Tile>;
+
+#[derive(Debug, EnumSetType)]
+pub enum Pathway {
+    A, B
+}
+
+impl From<bool> for Pathway {
+    fn from(value: bool) -> Self {
+        if value { Self::A } else { Self::B }
+    }
+}
+
+impl Display for Pathway {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", if *self == Self::A { 'A' } else { 'B' })
+    }
+}
+
+type Pathways = EnumSet<Pathway>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Tile {
+    Border = b'X',
+    Blank = b'.',
+    VSplit = b'|',
+    HSplit = b'-',
+    FwdMirror = b'/',
+    BackMirror = b'\\',
+}
+
+impl Tile {
+    pub const fn all() -> [Tile; 6] {
+        [Tile::Border, Tile::Blank, Tile::VSplit, Tile::HSplit, Tile::FwdMirror, Tile::BackMirror]
+    }
+
+    pub fn ways_out(&self, way_in: Way) -> (Ways, Pathway) {
+        match self {
+            // A if beam splits, B if straight through
+            Tile::VSplit if way_in.is_horizontal() => (Way::verticals(), Pathway::A),
+            Tile::HSplit if way_in.is_vertical() => (Way::horizontals(), Pathway::A),
+            Tile::VSplit => (way_in.into(), Pathway::B),
+            Tile::HSplit => (way_in.into(), Pathway::B),
+            // A if hit top side, B if hit bottom side
+            Tile::FwdMirror => {
+                let pathway_a = (Way::Right | Way::Down).contains(way_in);
+                (way_in.mirror_45_pos().into(), pathway_a.into())
+            }
+            Tile::BackMirror => {
+                let pathway_a = (Way::Left | Way::Down).contains(way_in);
+                (way_in.mirror_45_neg().into(), pathway_a.into())
+            }
+            // A if runs vertical, B if runs horizontal
+            Tile::Blank => (way_in.into(), way_in.is_vertical().into()),
+            Tile::Border => (Ways::empty(), way_in.is_vertical().into()),
+        }
+    }
+}
+
+impl TryFrom<u8> for Tile {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        for t in Tile::all() {
+            if t as u8 == value {
+                return Ok(t);
+            }
+        }
+        Err(value)
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", *self as u8 as char)
+    }
+}
+
+fn energized(history: &Grid<Pathways>) -> usize {
+    history.iter_rows()
+        .flat_map(|row| row.iter())
+        .filter(|sides| !sides.is_empty())
+        .count()
+}
+
+// Tiles with energized ones as `#`
+fn beams(tiles: &Tiles, history: &Grid<Pathways>) -> Grid<u8> {
+    let mut grid = Grid::new_ascii(tiles.shape());
+    for (r, row) in history.iter_rows().enumerate() {
+        for (c, sides) in row.iter().enumerate() {
+            grid[(r, c)] = if sides.is_empty() { tiles[(r, c)] as u8 } else { b'#' };
+        }
+    }
+    grid
+}
+
+fn beam_colour(&tile: &u8) -> Rgb {
+    match tile {
+        b'#' => Rgb(255, 220, 60),
+        b'.' => Rgb::BLACK,
+        b'X' => Rgb(60, 60, 60),
+        _ => Rgb(120, 160, 255),
+    }
+}
+
+fn solve(tiles: &Tiles, pos: (usize, usize), way_in: Way) -> usize {
+    let mut history: Grid<Pathways> = Grid::new(tiles.shape());
+    let mut stack = vec![];
+    stack.push((pos, way_in));
+
+    'stack:
+    while let Some((mut pos, mut way_in)) = stack.pop() {
+        aoc::frame!(grid = beams(tiles, &history), beam_colour);
+        let (mut ways_out, mut side) = tiles[pos].ways_out(way_in);
+        while ways_out.len() == 1 {
+            // Optimized path
+            if !history[pos].insert(side) {
+                continue 'stack;
+            }
+            way_in = ways_out.iter().next().unwrap();
+            pos = way_in.step(pos);
+            (ways_out, side) = tiles[pos].ways_out(way_in);
+        }
+
+        if ways_out.is_empty() || !history[pos].insert(side) {
+            continue;
+        }
+
+        for way_out in ways_out {
+            stack.push((way_out.step(pos), way_out));
+        }
+    }
+
+    energized(&history)
+}
+
+fn perimeter((rows, cols): (usize, usize)) -> impl Iterator<Item=((usize, usize), Way)> {
+    let top = (1..cols - 1).map(move |c| ((1, c), Way::Down));
+    let bottom = (1..cols - 1).map(move |c| ((rows - 2, c), Way::Up));
+    let left = (1..rows - 1).map(move |r| ((r, 1), Way::Right));
+    let right = (1..rows - 1).map(move |r| ((r, cols - 2), Way::Left));
+
+    top.chain(bottom).chain(left).chain(right)
+}
+
+fn part1(tiles: &Tiles) -> usize {
+    solve(tiles, (1, 1), Way::Right)
+}
+
+fn part2(tiles: &Tiles) -> usize {
+    perimeter(tiles.shape())
+        .map(|(pos, way_in)| solve(tiles, pos, way_in))
+        .max()
+        .unwrap_or(0)
+}
+
+fn read_tiles<R: Read>(input: R) -> Result<Tiles, aoc::error::Error> {
+    let mut reader = BufReader::new(input);
+    read_grid(&mut reader, Some(Tile::Border))
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Tiles;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        read_tiles(input.as_bytes())
+    }
+
+    fn part1(tiles: &Self::Input) -> Result<usize, aoc::error::Error> {
+        Ok(part1(tiles))
+    }
+
+    fn part2(tiles: &Self::Input) -> Result<usize, aoc::error::Error> {
+        Ok(part2(tiles))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc!{r"
+        .|...\....
+        |.-.\.....
+        .....|-...
+        ........|.
+        ..........
+        .........\
+        ..../.\\..
+        .-.-/..|..
+        .|....-|.\
+        ..//.|....
+    "};
+
+    #[test]
+    fn part1_example() {
+        let tiles = read_tiles(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part1(&tiles);
+        assert_eq!(answer, 46);
+    }
+
+    #[test]
+    fn part2_example() {
+        let tiles = read_tiles(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part2(&tiles);
+        assert_eq!(answer, 51);
+    }
+}
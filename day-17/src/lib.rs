@@ -0,0 +1,217 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::io::{BufReader, Read};
+
+use aoc::error::{no_solution, Error};
+use aoc::grid::{Rgb, Way, Ways, read_grid};
+use aoc::search::{dijkstra, Path};
+use aoc::solution::Solution;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct State {
+    pos: (usize, usize),
+    way_in: Way,
+    cost: u8,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Tile {
+    heat_loss: u8,
+}
+
+impl TryFrom<u8> for Tile {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, u8> {
+        if value.is_ascii_digit() {
+            Ok(Self { heat_loss: value - b'0' })
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.heat_loss)
+    }
+}
+
+pub type Grid = aoc::grid::Grid<Tile>;
+
+fn neighbours<'g>(grid: &'g Grid, old_state: &State) -> impl Iterator<Item=State> + 'g {
+    let State { pos: old_pos, way_in: old_way_in, cost: old_cost } = *old_state;
+
+    Ways::all().iter()
+        .filter(move |&new_way| new_way != old_way_in.flipped())
+        .flat_map(move |new_way| grid.step(old_pos, new_way).map(|new_pos|
+            State {
+                pos: new_pos,
+                way_in: new_way,
+                cost: if new_way == old_way_in { old_cost + 1 } else { 1 },
+            }
+        ))
+}
+
+fn edge(grid: &Grid, _old_state: State, new_state: State) -> u32 {
+    grid[new_state.pos].heat_loss as u32
+}
+
+fn min_heat_loss<F, G>(grid: &Grid, start: (usize, usize), accept: F, is_end: G) -> Result<u32, Error>
+where
+    F: Fn(&State, &State) -> bool,
+    G: FnMut(&State) -> bool,
+{
+    let starts = [
+        State { pos: start, way_in: Way::Down, cost: 1 },
+        State { pos: start, way_in: Way::Right, cost: 1 },
+    ];
+
+    let accept = &accept;
+    let path = dijkstra(
+        starts,
+        |&u| neighbours(grid, &u)
+            .filter(move |v| accept(&u, v))
+            .map(move |v| (v, edge(grid, u, v))),
+        is_end
+    );
+
+    let path = path.ok_or_else(|| no_solution("End not reached"))?;
+    aoc::frame!(grid = path_overlay(grid, &path), heat_colour);
+    Ok(path.cost)
+}
+
+// Heat losses as digits, with the path taken drawn over them
+fn path_overlay(grid: &Grid, path: &Path<State, u32>) -> aoc::grid::Grid<u8> {
+    let mut overlay = aoc::grid::Grid::new_ascii(grid.shape());
+    for (r, row) in grid.iter_rows().enumerate() {
+        for (c, tile) in row.iter().enumerate() {
+            overlay[(r, c)] = b'0' + tile.heat_loss;
+        }
+    }
+
+    for state in &path.states[1..] {
+        overlay[state.pos] = match state.way_in {
+            Way::Up => b'^',
+            Way::Right => b'>',
+            Way::Down => b'v',
+            Way::Left => b'<',
+        };
+    }
+    overlay
+}
+
+// Hotter blocks redder, the path in blue
+fn heat_colour(&cell: &u8) -> Rgb {
+    match cell {
+        b'1'..=b'9' => {
+            let heat = (cell - b'0') * 25;
+            Rgb(30 + heat, 30, 30)
+        }
+        _ => Rgb(80, 160, 255),
+    }
+}
+
+fn part1(grid: &Grid) -> Result<usize, Error> {
+    let start = (0, 0);
+    let end = (grid.shape().0 - 1, grid.shape().1 - 1);
+    min_heat_loss(
+        grid, start,
+        |old, new| old.cost < 3 || new.way_in != old.way_in,
+        |new| new.pos == end
+    ).map(|loss| loss as usize)
+}
+
+fn part2(grid: &Grid) -> Result<usize, Error> {
+    let start = (0, 0);
+    let end = (grid.shape().0 - 1, grid.shape().1 - 1);
+    min_heat_loss(
+        grid, start,
+        |old, new| (old.cost > 3 || new.way_in == old.way_in) && (old.cost < 10 || new.way_in != old.way_in),
+        |new| new.pos == end && new.cost > 3
+    ).map(|loss| loss as usize)
+}
+
+fn read_city<R: Read>(input: R) -> Result<Grid, Error> {
+    let mut reader = BufReader::new(input);
+    read_grid(&mut reader, None)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        read_city(input.as_bytes())
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize, Error> {
+        part1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize, Error> {
+        part2(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use indoc::indoc;
+
+    const EXAMPLE1: &str = indoc! {r"
+        2413432311323
+        3215453535623
+        3255245654254
+        3446585845452
+        4546657867536
+        1438598798454
+        4457876987766
+        3637877979653
+        4654967986887
+        4564679986453
+        1224686865563
+        2546548887735
+        4322674655533
+    "};
+
+    const EXAMPLE2: &str = indoc! {r"
+        111111111111
+        999999999991
+        999999999991
+        999999999991
+        999999999991
+    "};
+
+    #[test]
+    fn part1_example() {
+        let grid = read_city(Cursor::new(EXAMPLE1)).unwrap();
+        let answer = part1(&grid).unwrap();
+        assert_eq!(answer, 102);
+    }
+
+    #[test]
+    fn part2_example1() {
+        let grid = read_city(Cursor::new(EXAMPLE1)).unwrap();
+        let answer = part2(&grid).unwrap();
+        assert_eq!(answer, 94);
+    }
+
+    #[test]
+    fn part2_example2() {
+        let grid = read_city(Cursor::new(EXAMPLE2)).unwrap();
+        let answer = part2(&grid).unwrap();
+        assert_eq!(answer, 71);
+    }
+
+    #[test]
+    fn end_not_reached() {
+        let grid = read_city(Cursor::new("111\n")).unwrap();
+        assert!(part2(&grid).is_err());
+    }
+}
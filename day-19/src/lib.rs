@@ -1,8 +1,10 @@
 mod parse;
 mod parts;
+mod solution;
 mod workflow;
 
 pub use parse::read_system;
 pub use parse::read_parts;
 pub use parts::{Part, RangedPart};
+pub use solution::Day19;
 pub type Range = aoc::range::Range<u64>;
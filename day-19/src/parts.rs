@@ -1,13 +1,24 @@
 use std::ops::{Index, IndexMut};
-use crate::Range;
+use aoc::range::Boxed;
+use aoc_derive::FromFormat;
 
 pub type Part = Ratings<u64>;
-pub type RangedPart = Ratings<Range>;
-
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub enum Category { X, M, A, S }
+/// All parts with each rating in a range
+pub type RangedPart = Boxed<u64, CATS>;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, FromFormat)]
+pub enum Category {
+    #[aoc(format = "x")]
+    X,
+    #[aoc(format = "m")]
+    M,
+    #[aoc(format = "a")]
+    A,
+    #[aoc(format = "s")]
+    S,
+}
 
-const CATS: usize = 4;
+pub(crate) const CATS: usize = 4;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct Ratings<T> {
@@ -47,17 +58,3 @@ impl Part {
         self.xmas.iter().sum()
     }
 }
-
-impl RangedPart {
-    pub const fn all(range: Range) -> Self {
-        Self { xmas: [range; CATS] }
-    }
-
-    pub fn is_empty(&self) -> bool {
-        self.xmas.iter().any(|x| x.is_empty())
-    }
-
-    pub fn product(&self) -> u64 {
-        self.xmas.iter().map(|x| x.len()).product()
-    }
-}
@@ -0,0 +1,106 @@
+use std::io::{BufReader, Read};
+use aoc::parse::Sections;
+use aoc::range::Range;
+use aoc::solution::Solution;
+
+use crate::{Part, RangedPart, read_parts, read_system};
+use crate::workflow::PartsSystem;
+
+fn read_input<R: Read>(input: R) -> Result<(PartsSystem, Vec<Part>), aoc::error::Error> {
+    let mut sections = Sections::new(BufReader::new(input));
+    let system = read_system(&sections.expect("workflows")?)?;
+    let parts = read_parts(&sections.expect("parts")?)?;
+    sections.end()?;
+    Ok((system, parts))
+}
+
+fn part1(system: &PartsSystem, parts: &[Part]) -> Result<u64, aoc::error::Error> {
+    parts.iter().map(|p| system.value(p)).sum()
+}
+
+fn part2(system: &PartsSystem) -> Result<u64, aoc::error::Error> {
+    let part = RangedPart::uniform(Range::inclusive(1, 4000));
+    system.combinations(&part)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = (PartsSystem, Vec<Part>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        read_input(input.as_bytes())
+    }
+
+    fn part1((system, parts): &Self::Input) -> Result<u64, aoc::error::Error> {
+        part1(system, parts)
+    }
+
+    fn part2((system, _parts): &Self::Input) -> Result<u64, aoc::error::Error> {
+        part2(system)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc!{r"
+        px{a<2006:qkq,m>2090:A,rfg}
+        pv{a>1716:R,A}
+        lnx{m>1548:A,A}
+        rfg{s<537:gd,x>2440:R,A}
+        qs{s>3448:A,lnx}
+        qkq{x<1416:A,crn}
+        crn{x>2662:A,R}
+        in{s<1351:px,qqz}
+        qqz{s>2770:qs,m<1801:hdj,R}
+        gd{a>3333:R,R}
+        hdj{m>838:A,pv}
+
+        {x=787,m=2655,a=1222,s=2876}
+        {x=1679,m=44,a=2067,s=496}
+        {x=2036,m=264,a=79,s=2244}
+        {x=2461,m=1339,a=466,s=291}
+        {x=2127,m=1623,a=2188,s=1013}
+    "};
+
+    #[test]
+    fn part1_example() {
+        let (system, parts) = read_input(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part1(&system, &parts).unwrap();
+        assert_eq!(answer, 19114);
+    }
+
+    #[test]
+    fn part2_example() {
+        let (system, _parts) = read_input(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part2(&system).unwrap();
+        assert_eq!(answer, 167409079868000);
+    }
+
+    #[test]
+    fn unsolvable_systems() {
+        let (system, parts) = read_input(Cursor::new("in{x<10:A,next}\nnext{a>5:in,R}\n\n{x=787,m=2655,a=1222,s=2876}\n")).unwrap();
+        assert!(part1(&system, &parts).is_err());
+
+        let (system, parts) = read_input(Cursor::new("px{a<2006:A,R}\n\n{x=787,m=2655,a=1222,s=2876}\n")).unwrap();
+        assert!(part1(&system, &parts).is_err());
+        assert!(part2(&system).is_err());
+    }
+
+    #[test]
+    fn bad_part_location() {
+        let input = EXAMPLE.replace("{x=2036,", "{q=2036,");
+        let location = read_input(Cursor::new(input)).unwrap_err().location().cloned().unwrap();
+        assert_eq!((location.line, location.column()), (15, 2));
+
+        assert!(read_input(Cursor::new("px{a<2006:A,R}\n")).is_err());
+    }
+}
@@ -1,8 +1,9 @@
 #![allow(clippy::redundant_field_names)]
 
 use std::collections::HashMap;
+use aoc::error::{no_solution, Error};
+use aoc_derive::FromFormat;
 use crate::parts::{Category, Part, RangedPart};
-use crate::Range;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Target {
@@ -18,10 +19,13 @@ pub struct RangedPartTarget {
     target: Target,
 }
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, FromFormat)]
 pub enum Op {
+    #[aoc(format = "<")]
     LessThan,
+    #[aoc(format = ">")]
     GreaterThan,
+    #[aoc(format = "T")]
     True,
 }
 
@@ -58,24 +62,15 @@ impl Rule {
         }
     }
 
-    fn part_with_range(&self, part: &RangedPart, range: Range) -> RangedPart {
-        let mut p = *part;
-        p[self.category] = range;
-        p
-    }
-
     pub fn split(&self, part: &RangedPart) -> (RangedPartTarget, RangedPartTarget) {
-        let range = part[&self.category];
-
+        let axis = self.category as usize;
         let split_value = match self.op {
             Op::LessThan => self.value,
             Op::GreaterThan => self.value + 1,
-            Op::True => range.end(),
+            Op::True => part[axis].end(),
         };
 
-        let (a, b) = range.split_at(split_value);
-        let mut pa = self.part_with_range(part, a);
-        let mut pb = self.part_with_range(part, b);
+        let (mut pa, mut pb) = part.split_at(axis, split_value);
         if self.op == Op::GreaterThan {
             (pa, pb) = (pb, pa)
         }
@@ -160,20 +155,28 @@ impl PartsSystem {
         Self { workflows, index }
     }
 
-    pub fn value(&self, part: &Part) -> u64 {
-        let mut ix = self.index["in"];
+    fn workflow_index(&self, name: &str) -> Result<usize, Error> {
+        self.index.get(name).copied().ok_or_else(|| no_solution(format!("No workflow '{name}'")))
+    }
+
+    fn workflow(&self, ix: usize) -> Result<&Workflow, Error> {
+        self.workflows.get(ix).ok_or_else(|| no_solution("Passed the last workflow"))
+    }
+
+    pub fn value(&self, part: &Part) -> Result<u64, Error> {
+        let mut ix = self.workflow_index("in")?;
         for _ in 0..Self::MAX_STEPS {
-            let workflow = &self.workflows[ix];
+            let workflow = self.workflow(ix)?;
             let target = workflow.apply(part);
             match target {
                 Target::Accept => {
-                    return part.sum();
+                    return Ok(part.sum());
                 },
                 Target::Reject => {
-                    return 0;
+                    return Ok(0);
                 },
                 Target::Workflow(w) => {
-                    ix = self.index[w];
+                    ix = self.workflow_index(w)?;
                 },
                 Target::Pass => {
                     ix += 1;
@@ -181,27 +184,27 @@ impl PartsSystem {
             }
         }
 
-        panic!("Exceeded max {} steps", Self::MAX_STEPS)
+        Err(no_solution(format!("Exceeded max {} steps", Self::MAX_STEPS)))
     }
 
-    pub fn combinations(&self, part: &RangedPart) -> u64 {
+    pub fn combinations(&self, part: &RangedPart) -> Result<u64, Error> {
         let mut sum = 0;
         let mut stack: Vec<(usize, RangedPart)> = Vec::new();
-        stack.push((self.index["in"], *part));
+        stack.push((self.workflow_index("in")?, *part));
 
         let mut steps = 0;
         while let Some((workflow_ix, part)) = stack.pop() {
-            let workflow = &self.workflows[workflow_ix];
+            let workflow = self.workflow(workflow_ix)?;
             let ranged_targets = workflow.split(&part);
             for target in ranged_targets {
                 match target.target {
                     Target::Accept => {
-                        sum += target.part.product();
+                        sum += target.part.volume();
                     },
                     Target::Reject => {
                     },
                     Target::Workflow(w) => {
-                        stack.push((self.index[&w], target.part));
+                        stack.push((self.workflow_index(&w)?, target.part));
                     },
                     Target::Pass => {
                         stack.push((workflow_ix + 1, part));
@@ -211,10 +214,10 @@ impl PartsSystem {
 
             steps += 1;
             if steps > Self::MAX_STEPS {
-                panic!("Exceeded max {} steps", Self::MAX_STEPS);
+                return Err(no_solution(format!("Exceeded max {} steps", Self::MAX_STEPS)));
             }
         }
 
-        sum
+        Ok(sum)
     }
 }
@@ -1,13 +1,17 @@
 #![allow(clippy::redundant_field_names)]
 
 use std::collections::{HashMap, VecDeque};
-use std::iter;
+use std::fmt::{Display, Formatter};
 use std::ops::{Add, AddAssign, Mul, MulAssign, Sub};
 
 use itertools::Itertools;
-use aoc::cycle::find_in_cycle;
+use aoc::cycle::extrapolate;
+use aoc::error::{no_solution, Error};
 
 pub mod parse;
+mod solution;
+
+pub use solution::Day20;
 
 type CommsModuleId = usize;
 
@@ -161,7 +165,7 @@ impl Mul<LowHighCount> for usize {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CommsSystem {
     comms: Vec<CommsModule>,
     index: HashMap<String, CommsModuleId>,
@@ -169,6 +173,8 @@ pub struct CommsSystem {
 }
 
 impl CommsSystem {
+    const MAX_PUSHES: usize = 1_000_000;
+
     fn new() -> Self {
         Self { comms: Vec::new(), index: HashMap::new(), pending: VecDeque::new() }
     }
@@ -194,8 +200,9 @@ impl CommsSystem {
         self.comms[id2].add_incoming(id1);
     }
 
-    fn broadcast_module(&self) -> usize {
-        self.index["broadcaster"]
+    // Without a broadcaster the button's pulse goes nowhere
+    fn broadcast_module(&self) -> Option<CommsModuleId> {
+        self.index.get("broadcaster").copied()
     }
 
     fn fire(&mut self, sender_id: CommsModuleId, receiver_id: CommsModuleId, pulse: Pulse) {
@@ -209,7 +216,7 @@ impl CommsSystem {
     pub fn push_button(&mut self) -> LowHighCount {
         let mut total = LowHighCount { low: 1, high: 0 };
 
-        self.pending.push_back((self.broadcast_module(), LOW));
+        self.pending.extend(self.broadcast_module().map(|id| (id, LOW)));
         while let Some((sender_id, pulse_in)) = self.pending.pop_front() {
             let receiver_ids = self.comms[sender_id].outgoing.clone();
             for receiver_id in receiver_ids {
@@ -222,13 +229,14 @@ impl CommsSystem {
             }
         }
 
+        aoc::frame!("{self}");
         total
     }
 
     pub fn push_button_and_wait_until<F>(&mut self, mut until_test: F) -> ControlFlow
         where F: FnMut(CommsModuleId, CommsModuleId, Pulse) -> bool
     {
-        self.pending.push_back((self.broadcast_module(), LOW));
+        self.pending.extend(self.broadcast_module().map(|id| (id, LOW)));
 
         while let Some((sender_id, pulse_in)) = self.pending.pop_front() {
             let receiver_ids = self.comms[sender_id].outgoing.clone();
@@ -241,51 +249,55 @@ impl CommsSystem {
             }
         }
 
+        aoc::frame!("{self}");
         ControlFlow::Continue
     }
 
-    fn state(&mut self) -> Vec<CommsModuleState> {
-        self.comms.iter().map(|c| c.state.clone()).collect_vec()
-    }
-
-    pub fn run_part1(&mut self, button_pushes: usize) -> LowHighCount {
-        let mut low_high = LowHighCount::default();
-        let it = iter::repeat(()).map(|_| {
-            let next = (self.state(), low_high);
-            low_high += self.push_button();
-            next
-        });
-
-        match find_in_cycle(it, button_pushes) {
-            Ok(cycle) => {
-                let cycle_delta_low_high = cycle.end.1 - cycle.start.1;
-                cycle.target_equiv.1 + cycle_delta_low_high * cycle.complete_cycles()
-            }
-            Err(no_cycle) => no_cycle.target.1,
-        }
+    pub fn run_part1(&self, button_pushes: usize) -> LowHighCount {
+        let (_, low_high) = extrapolate(self.clone(), CommsSystem::push_button, button_pushes);
+        low_high
     }
 
-    pub fn run_part2(&mut self) -> usize {
-        let rx = self.index["rx"];
+    pub fn run_part2(&mut self) -> Result<usize, Error> {
+        let rx = *self.index.get("rx").ok_or_else(|| no_solution("No 'rx' module"))?;
         let rx_in = self.comms[rx].incoming.iter()
             .copied()
             .exactly_one()
-            .expect("Expect exactly one node to rx");
+            .map_err(|_| no_solution("Expect exactly one node to rx"))?;
 
         let conjunctions = self.comms[rx_in].incoming.clone();
 
         conjunctions.into_iter().map(|comm| {
-            assert!(matches!(self.comms[comm].state, CommsModuleState::Conjunction(_)));
+            if !matches!(self.comms[comm].state, CommsModuleState::Conjunction(_)) {
+                return Err(no_solution(format!("Expect conjunctions before rx, not {}", self.comms[comm].name)));
+            }
+
             self.reset();
-            iter::repeat(()).map(|_| {
-                self.push_button_and_wait_until(|sender_id, receiver_id, pulse| {
-                    sender_id == comm && receiver_id == rx_in && pulse == HIGH
+            (1..=Self::MAX_PUSHES)
+                .find(|_| {
+                    self.push_button_and_wait_until(|sender_id, receiver_id, pulse| {
+                        sender_id == comm && receiver_id == rx_in && pulse == HIGH
+                    }) == ControlFlow::Break
                 })
-            })
-            .take_while_inclusive(|&outcome| outcome == ControlFlow::Continue)
-            .count()
+                .ok_or_else(|| no_solution(format!("No high pulse from {} in {} pushes",
+                                                   self.comms[comm].name, Self::MAX_PUSHES)))
         })
-        .reduce(num::integer::lcm)
-        .expect("No nodes to rx")
+        .reduce(|a, b| Ok(num::integer::lcm(a?, b?)))
+        .unwrap_or_else(|| Err(no_solution("No nodes to rx")))
+    }
+}
+
+/// Flip-flops as on (1) or off (0), conjunctions by the last pulse from each input
+impl Display for CommsSystem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let bits = |pulses: &[Pulse]| pulses.iter().map(|&p| if p { '1' } else { '0' }).collect::<String>();
+        for comm in &self.comms {
+            match &comm.state {
+                CommsModuleState::FlipFlop(on) => writeln!(f, "%{} {}", comm.name, bits(&[*on]))?,
+                CommsModuleState::Conjunction(pulses) => writeln!(f, "&{} {}", comm.name, bits(pulses))?,
+                CommsModuleState::Output | CommsModuleState::Broadcast => {}
+            }
+        }
+        Ok(())
     }
 }
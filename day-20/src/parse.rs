@@ -1,24 +1,29 @@
 use std::io::{BufRead, BufReader, Read, Seek};
 use std::str::FromStr;
 
-use aoc::parse::{OkOrErr, ParseExt};
+use nom::character::complete::one_of;
+use nom::combinator::{opt, recognize};
+use nom::sequence::pair;
+
+use aoc::parse::{AtLine, OkOrErr, ParseExt};
+use aoc::parse::combinators::{adjacency, parse_all, word};
 
 use crate::{CommsModule, CommsModuleType, CommsSystem};
 
 impl FromStr for CommsModuleType {
-    type Err = String;
+    type Err = aoc::error::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "%" => Ok(Self::FlipFlop),
             "&" => Ok(Self::Conjunction),
-            _ => Err(s.to_owned()),
+            _ => Err(s.into()),
         }
     }
 }
 
 impl FromStr for CommsModule {
-    type Err = String;
+    type Err = aoc::error::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let ix = s.chars().next().ok_or_err(s)?.len_utf8();
@@ -35,42 +40,36 @@ impl FromStr for CommsModule {
     }
 }
 
+/// Parses `<module> -> <name>, ...`, giving the module and the names it sends to
+fn parse_line(line: &str) -> Result<(CommsModule, Vec<&str>), aoc::error::Error> {
+    let module = recognize(pair(opt(one_of("%&")), word));
+    let (module, connections) = parse_all(line, adjacency(module, word))?;
+    Ok((module.please(line)?, connections))
+}
+
 pub fn read_system<R: Read + Seek>(input: R) -> Result<CommsSystem, aoc::error::Error> {
     let input = &mut BufReader::new(input);
     let mut system = CommsSystem::new();
 
-    for line in input.lines() {
+    for (i, line) in input.lines().enumerate() {
         let line = &line?;
         if line.is_empty() {
             continue;
         }
 
-        let mut parts = line.splitn(2, "->");
-        let comms: CommsModule = parts.next()
-            .ok_or_err(line)?
-            .trim()
-            .please(line)?;
+        let (comms, _) = parse_line(line).at_line(i + 1, line)?;
         system.add(comms);
     }
 
     input.rewind()?;
 
-    for line in input.lines() {
+    for (i, line) in input.lines().enumerate() {
         let line = &line?;
         if line.is_empty() {
             continue;
         }
 
-        let mut parts = line.splitn(2, "->");
-        let comms: CommsModule = parts.next()
-            .ok_or_err(line)?
-            .trim()
-            .please(line)?;
-        let connections: Vec<&str> = parts.next()
-            .ok_or_err(line)?
-            .split(',')
-            .map(|s| s.trim())
-            .collect();
+        let (comms, connections) = parse_line(line).at_line(i + 1, line)?;
 
         let name = comms.name.to_owned();
         for c in connections {
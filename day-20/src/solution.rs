@@ -0,0 +1,83 @@
+use std::io::Cursor;
+use aoc::solution::Solution;
+
+use crate::CommsSystem;
+use crate::parse::read_system;
+
+fn part1(system: CommsSystem) -> usize {
+    let low_high = system.run_part1(1000);
+    low_high.low * low_high.high
+}
+
+fn part2(mut system: CommsSystem) -> Result<usize, aoc::error::Error> {
+    system.run_part2()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = CommsSystem;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        read_system(Cursor::new(input))
+    }
+
+    fn part1(system: &Self::Input) -> Result<usize, aoc::error::Error> {
+        Ok(part1(system.clone()))
+    }
+
+    fn part2(system: &Self::Input) -> Result<usize, aoc::error::Error> {
+        part2(system.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    const EXAMPLE1: &str = indoc!{r"
+        broadcaster -> a, b, c
+        %a -> b
+        %b -> c
+        %c -> inv
+        &inv -> a
+    "};
+
+    const EXAMPLE2: &str = indoc!{r"
+        broadcaster -> a
+        %a -> inv, con
+        &inv -> b
+        %b -> con
+        &con -> output
+    "};
+
+    #[test]
+    fn part1_example1() {
+        let system = read_system(Cursor::new(EXAMPLE1)).unwrap();
+        let answer = part1(system);
+        assert_eq!(answer, 32000000);
+    }
+
+    #[test]
+    fn part1_example2() {
+        let system = read_system(Cursor::new(EXAMPLE2)).unwrap();
+        let answer = part1(system);
+        assert_eq!(answer, 11687500);
+    }
+
+    #[test]
+    fn part2_without_rx() {
+        let system = read_system(Cursor::new(EXAMPLE2)).unwrap();
+        assert!(matches!(part2(system), Err(aoc::error::Error::NoSolution(_))));
+
+        let system = read_system(Cursor::new("%a -> rx\n")).unwrap();
+        assert!(part2(system).is_err());
+        let system = read_system(Cursor::new("%a -> b\n")).unwrap();
+        assert_eq!(part1(system), 0);
+    }
+}
@@ -0,0 +1,241 @@
+use std::io::{BufReader, Read};
+use std::iter;
+
+use aoc::CollectArray;
+use aoc::error::{no_solution, Error};
+use aoc::grid::{read_grid_ascii, Rgb, Ways};
+use aoc::search::bfs_layers;
+use aoc::solution::Solution;
+
+pub type Grid = aoc::grid::Grid<u8>;
+
+type Ordinate = isize;
+type Coords = (Ordinate, Ordinate);
+
+const fn signed((r, c): (usize, usize)) -> Coords {
+    (r as Ordinate, c as Ordinate)
+}
+
+const fn unsigned((r, c): Coords) -> (usize, usize) {
+    (r as usize, c as usize)
+}
+
+fn start(grid: &Grid) -> Result<Coords, Error> {
+    for (r, row) in grid.iter_rows().enumerate() {
+        for (c, &tile) in row.iter().enumerate() {
+            if tile == b'S' {
+                return Ok(signed((r, c)));
+            }
+        }
+    }
+
+    Err(no_solution("No start tile"))
+}
+
+fn quadratic_fit(y: [f64; 3]) -> [f64; 3] {
+    // y = a₀x² + a₁x + a₂
+    // Solve for (a₀, a₁, a₂) given satisfying (x, y) = (0, y₀), (1, y₁), (2, y₂)
+    //
+    // Output a = V⁻¹y where V is the Vandemonde matrix
+    //     ┌          ┐   ┌       ┐
+    //     │ x₀² x₀ 1 │   │ 0 0 1 │
+    // V = │ x₁² x₁ 1 │ = │ 1 1 1 │
+    //     │ x₂² x₂ 1 │   │ 4 2 1 │
+    //     └          ┘   └       ┘
+    // https://en.wikipedia.org/wiki/Vandermonde_matrix#Applications
+    // https://en.wikipedia.org/wiki/Cramer%27s_rule
+    let det_v = -2.0;
+    let det_v0 = -y[0] + 2.0 * y[1] - y[2];
+    let det_v1 = 3.0 * y[0] - 4.0 * y[1] + y[2];
+    let det_v2 = -2.0 * y[0];
+    [det_v0 / det_v, det_v1 / det_v, det_v2 / det_v]
+}
+
+// Number of plots reachable in exactly 1, 2, 3... steps. A plot first reached in `d` steps
+// is also reachable in `d + 2` and so on, by stepping away and back.
+fn plot_counts<'g, F, I>(grid: &'g Grid, start: Coords, neighbours: F) -> impl Iterator<Item=usize> + 'g
+    where
+        F: Fn(&'g Grid, Coords) -> I + 'g,
+        I: Iterator<Item=Coords> + 'g
+{
+    // By parity of the number of steps
+    let mut totals = [0, 0];
+    let mut plots = [Vec::new(), Vec::new()];
+    bfs_layers([start], move |&pos| neighbours(grid, pos))
+        // Once every plot has been reached, the totals alternate
+        .chain(iter::repeat_with(Vec::new))
+        .enumerate()
+        .map(move |(steps, layer)| {
+            totals[steps % 2] += layer.len();
+            if aoc::frames::recording() {
+                plots[steps % 2].extend(layer);
+                aoc::frame!(grid = reached(grid, &plots[steps % 2]), plot_colour);
+            }
+            totals[steps % 2]
+        })
+        .skip(1)
+}
+
+// Plots reached marked `O`. Only the original garden is shown, so that frames stay the same size.
+fn reached(grid: &Grid, plots: &[Coords]) -> Grid {
+    let mut garden = grid.clone();
+    let (rows, cols) = signed(grid.shape());
+    for &(r, c) in plots {
+        if (0..rows).contains(&r) && (0..cols).contains(&c) {
+            garden[unsigned((r, c))] = b'O';
+        }
+    }
+    garden
+}
+
+fn plot_colour(&tile: &u8) -> Rgb {
+    match tile {
+        b'O' => Rgb(80, 200, 80),
+        b'#' => Rgb(60, 60, 60),
+        _ => Rgb::WHITE,
+    }
+}
+
+fn neighbours_part1(grid: &Grid, prev: Coords) -> impl Iterator<Item=Coords> + '_ {
+    Ways::all().iter()
+        .filter_map(move |way| grid.step(unsigned(prev), way))
+        .filter(|&pos| grid[pos] != b'#')
+        .map(signed)
+}
+
+fn neighbours_part2(grid: &Grid, prev: Coords) -> impl Iterator<Item=Coords> + '_ {
+    // An empty garden has nowhere to go
+    grid.wrapping().into_iter().flat_map(move |garden| Ways::all().iter()
+        .map(move |way| garden.step(prev, way))
+        .filter(move |&pos| garden[pos] != b'#'))
+}
+
+fn part1(grid: &Grid, max_dist: usize) -> Result<usize, Error> {
+    let start = start(grid)?;
+    Ok(plot_counts(grid, start, neighbours_part1)
+        .take(max_dist)
+        .last()
+        // With no steps taken only the start is reachable
+        .unwrap_or(1))
+}
+
+#[cfg(test)]
+fn part2_test(grid: &Grid, max_dist: usize) -> Result<usize, Error> {
+    let start = start(grid)?;
+    Ok(plot_counts(grid, start, neighbours_part2)
+        .take(max_dist)
+        .last()
+        .unwrap_or(1))
+}
+
+fn part2_real(grid: &Grid, max_dist: usize) -> Result<usize, Error> {
+    let grid_size = grid.shape().0;
+    let grid_size_half = grid_size / 2;
+    if grid.shape() != (131, 131) {
+        return Err(no_solution("Part 2 requires specially crafted input 🤷"));
+    }
+
+    let start = (grid_size_half, grid_size_half);
+    if grid[start] != b'S' {
+        return Err(no_solution("Start must be in the centre 🤷"));
+    }
+
+    aoc::diag!("BFS...");
+    let y = plot_counts(grid, signed(start), neighbours_part2)
+        .enumerate()
+        .filter(|&(i, _)| (i + 1) % grid_size == grid_size_half)
+        .take(3)
+        .map(|(_, d)| d as f64)
+        .inspect(|x| aoc::diag!("{}", x))
+        .try_collect_array()?;
+
+    let n = ((max_dist - grid_size_half) / grid_size) as Ordinate;
+    aoc::diag!("Solving...");
+    let a = quadratic_fit(y).map(|v| v as isize);
+    let result = a[0] * n * n + a[1] * n + a[2];
+
+    result.try_into().map_err(|_| no_solution(format!("Negative plot count {result}")))
+}
+
+fn read_garden<R: Read>(input: R) -> Result<Grid, aoc::error::Error> {
+    let mut reader = BufReader::new(input);
+    read_grid_ascii(&mut reader, None)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        read_garden(input.as_bytes())
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize, aoc::error::Error> {
+        part1(grid, 64)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize, aoc::error::Error> {
+        part2_real(grid, 26501365)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc!{r"
+        ...........
+        .....###.#.
+        .###.##..#.
+        ..#.#...#..
+        ....#.#....
+        .##..S####.
+        .##..#...#.
+        .......##..
+        .##.#.####.
+        .##..##.##.
+        ...........
+    "};
+
+    #[test]
+    fn part1_example() {
+        let grid = read_garden(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part1(&grid, 6).unwrap();
+        assert_eq!(answer, 16);
+    }
+
+    #[test]
+    fn part2_example_0006() {
+        let grid = read_garden(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part2_test(&grid, 6).unwrap();
+        assert_eq!(answer, 16);
+    }
+
+    #[test]
+    fn part2_example_0010() {
+        let grid = read_garden(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part2_test(&grid, 10).unwrap();
+        assert_eq!(answer, 50);
+    }
+
+    #[test]
+    fn part2_example_0050() {
+        let grid = read_garden(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part2_test(&grid, 50).unwrap();
+        assert_eq!(answer, 1594);
+    }
+
+    #[test]
+    fn part2_example_0100() {
+        let grid = read_garden(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part2_test(&grid, 100).unwrap();
+        assert_eq!(answer, 6536);
+    }
+}
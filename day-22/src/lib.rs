@@ -4,12 +4,16 @@ use std::io::{BufRead, BufReader, Read};
 
 use itertools::Itertools;
 
-use aoc::grid::Grid;
+use aoc::grid::{Grid, Rgb};
+use aoc::parse::AtLine;
 
 use crate::world::{BoundingBox, Vec3, X, Y, Z};
 
+mod solution;
 mod world;
 
+pub use solution::Day22;
+
 type Brick = BoundingBox;
 
 #[derive(Debug, Clone)]
@@ -41,16 +45,18 @@ impl Bricks {
 
     pub fn settle(&mut self) {
         let mut result = Self::new();
+        let bb = self.bounding_box;
 
         for (mut brick, fall) in self.falling(None).map(|(b, f)| (*b, f)) {
             brick -= Vec3::new(0, 0, fall);
             result.add(brick);
+            aoc::frame!(grid = result.side_view(&bb), brick_colour);
         }
 
         *self = result;
     }
 
-    pub fn falling<'b>(&'b self, without: Option<&'b Brick>) -> impl Iterator<Item=(&Brick, u64)> {
+    pub fn falling<'b>(&'b self, without: Option<&'b Brick>) -> impl Iterator<Item=(&'b Brick, u64)> {
         let bb = self.bounding_box;
         let mut height_map = Grid::new((
             (bb[1][X] - bb[0][X]) as usize,
@@ -127,6 +133,23 @@ impl Bricks {
     pub fn iter(&self) -> impl Iterator<Item=&Brick> {
         self.bricks_base.values().flatten()
     }
+
+    /// Looking along the y axis at the space `bb`, ground at the bottom
+    fn side_view(&self, bb: &BoundingBox) -> Grid<u8> {
+        let (rows, cols) = ((bb[1][Z] - bb[0][Z]) as usize, (bb[1][X] - bb[0][X]) as usize);
+        let mut grid = Grid::new_ascii((rows, cols));
+        for brick in self.iter() {
+            let ext = brick.extents();
+            for (x, z) in ext[X].into_iter().cartesian_product(ext[Z]) {
+                grid[(rows - 1 - (z - bb[0][Z]) as usize, (x - bb[0][X]) as usize)] = b'#';
+            }
+        }
+        grid
+    }
+}
+
+fn brick_colour(&cell: &u8) -> Rgb {
+    if cell == b'#' { Rgb(180, 90, 60) } else { Rgb::WHITE }
 }
 
 impl Default for Bricks {
@@ -164,9 +187,9 @@ fn group_by_z(bricks: &[Brick], top: bool) -> BTreeMap<u64, Vec<Brick>> {
 pub fn read_bricks<R: Read>(input: R) -> Result<Bricks, aoc::error::Error> {
     let lines = BufReader::new(input).lines();
     let mut bricks = Bricks::new();
-    for line in lines {
+    for (i, line) in lines.enumerate() {
         let line = line?;
-        let brick: Brick = line.parse()?;
+        let brick: Brick = line.parse().at_line(i + 1, &line)?;
         bricks.add(brick);
     }
 
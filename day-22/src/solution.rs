@@ -0,0 +1,88 @@
+use aoc::solution::Solution;
+
+use crate::{Bricks, read_bricks};
+
+fn part1(bricks: &Bricks) -> Result<u64, aoc::error::Error> {
+    let mut bricks = bricks.clone();
+    bricks.settle();
+
+    let total = bricks.iter()
+        .filter(|&support| bricks.falls_without(support).is_empty())
+        .count();
+
+    Ok(total as u64)
+}
+
+fn part2(bricks: &Bricks) -> Result<u64, aoc::error::Error> {
+    let mut bricks = bricks.clone();
+    bricks.settle();
+
+    let total: usize = bricks.iter()
+        .map(|brick| {
+            bricks.falling(Some(brick))
+                .filter(|&(_, fall)| fall > 0)
+                .count()
+        })
+        .sum();
+
+    Ok(total as u64)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input = Bricks;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        read_bricks(input.as_bytes())
+    }
+
+    fn part1(bricks: &Self::Input) -> Result<u64, aoc::error::Error> {
+        part1(bricks)
+    }
+
+    fn part2(bricks: &Self::Input) -> Result<u64, aoc::error::Error> {
+        part2(bricks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc!{r"
+        1,0,1~1,2,1
+        0,0,2~2,0,2
+        0,2,3~2,2,3
+        0,0,4~0,2,4
+        2,0,5~2,2,5
+        0,1,6~2,1,6
+        1,1,8~1,1,9
+    "};
+
+    #[test]
+    fn part1_example() {
+        let bricks = read_bricks(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part1(&bricks).unwrap();
+        assert_eq!(answer, 5);
+    }
+
+    #[test]
+    fn part2_example() {
+        let bricks = read_bricks(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part2(&bricks).unwrap();
+        assert_eq!(answer, 7);
+    }
+
+    #[test]
+    fn bad_brick() {
+        let e = read_bricks(Cursor::new("1,0,1~1,2,1\n0,0,2~2,0,2\n0,2,x~2,2,3\n")).unwrap_err();
+        assert_eq!(e.location().unwrap().line, 3);
+    }
+}
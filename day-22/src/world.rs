@@ -1,7 +1,8 @@
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
 use std::str::FromStr;
-use aoc::CollectArray;
+use aoc::range::Boxed;
+use aoc_derive::FromFormat;
 
 type Range = aoc::range::Range<u64>;
 
@@ -158,12 +159,12 @@ impl BoundingBox {
         self[1] = self[1].max_all(&other[1]);
     }
 
-    pub fn extents(&self) -> [Range; 3] {
-        [
+    pub fn extents(&self) -> Boxed<u64, 3> {
+        Boxed::new([
             Range::new(self[0][X], self[1][X]),
             Range::new(self[0][Y], self[1][Y]),
             Range::new(self[0][Z], self[1][Z]),
-        ]
+        ])
     }
 
     pub fn overlaps(&self, other: &Self, axis: usize) -> bool {
@@ -173,9 +174,7 @@ impl BoundingBox {
     }
 
     pub fn is_empty(&self) -> bool {
-        self[0][X] == self[1][X] ||
-            self[0][Y] == self[1][Y] ||
-            self[0][Z] == self[1][Z]
+        self.extents().is_empty()
     }
 }
 
@@ -223,29 +222,31 @@ impl SubAssign<Vec3> for BoundingBox {
     }
 }
 
+/// A brick as input: the coordinates of the cubes at either end
+#[derive(FromFormat)]
+#[aoc(format = "{x1},{y1},{z1}~{x2},{y2},{z2}")]
+struct BrickEnds {
+    x1: u64, y1: u64, z1: u64,
+    x2: u64, y2: u64, z2: u64,
+}
+
 impl FromStr for BoundingBox {
     type Err = aoc::error::Error;
 
     fn from_str(s: &str) -> Result<Self, aoc::error::Error> {
-        let [b0, b1] = s.splitn(2, '~')
-            .try_collect_array()?;
-        let bottom_left = parse_coord(b0)? - Vec3::new(0, 0, 1);
-        let top_right = parse_coord(b1)? + Vec3::new(1, 1, 0);
+        let ends: BrickEnds = s.parse()?;
+        let (x, y, z) = (
+            Range::try_inclusive(ends.x1, ends.x2)?,
+            Range::try_inclusive(ends.y1, ends.y2)?,
+            Range::try_inclusive(ends.z1, ends.z2)?,
+        );
+        // Each brick's base is the level beneath its lowest cubes
+        let bottom_left = Vec3::new(x.start(), y.start(), z.start()) - Vec3::new(0, 0, 1);
+        let top_right = Vec3::new(x.end(), y.end(), z.end()) - Vec3::new(0, 0, 1);
         Ok(BoundingBox::new(bottom_left, top_right))
     }
 }
 
-fn parse_coord(s: &str) -> Result<Vec3, aoc::error::Error> {
-    let coord_str: [_; 3] = s.splitn(3, ',')
-        .try_collect_array()?;
-
-    Ok(Vec3::new(
-        coord_str[X].parse()?,
-        coord_str[Y].parse()?,
-        coord_str[Z].parse()?,
-    ))
-}
-
 impl Display for BoundingBox {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let (bb0, bb1) = (self[0], self[1] - 1);
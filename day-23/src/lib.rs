@@ -11,7 +11,12 @@ use petgraph::graph::NodeIndex;
 use petgraph::prelude::EdgeRef;
 use petgraph::Undirected;
 
-use aoc::grid::{Way, Ways};
+use aoc::error::{no_solution, Error};
+use aoc::grid::{Rgb, Way, Ways};
+
+pub use solution::Day23;
+
+mod solution;
 
 pub type Ordinate = u8;
 pub type Coords = (Ordinate, Ordinate);
@@ -124,6 +129,46 @@ fn collapse_unique_edge(graph: &mut Graph, node: NodeIx, edge_weight_offset: u16
     other_node
 }
 
+// The maze with start and end marked, ready for junctions and the corridors between them
+fn junction_overlay(grid: &Grid, start: Coords, end: Coords) -> aoc::grid::Grid<u8> {
+    let mut overlay = aoc::grid::Grid::new_ascii(grid.shape());
+    for (r, row) in grid.iter_rows().enumerate() {
+        for (c, &tile) in row.iter().enumerate() {
+            overlay[(r, c)] = if tile == Tile::Forest { b'#' } else { b'.' };
+        }
+    }
+    overlay[usz(start)] = b'S';
+    overlay[usz(end)] = b'E';
+    overlay
+}
+
+// Adds a graph edge to the overlay and emits it as a frame
+fn draw_edge(overlay: Option<&mut aoc::grid::Grid<u8>>, corridor: &[Coords], junction: Option<Coords>) {
+    let Some(overlay) = overlay else {
+        return;
+    };
+
+    for &pos in corridor {
+        if overlay[usz(pos)] == b'.' {
+            overlay[usz(pos)] = b'+';
+        }
+    }
+    if let Some(pos) = junction {
+        overlay[usz(pos)] = b'O';
+    }
+
+    aoc::frame!(grid = overlay, junction_colour);
+}
+
+fn junction_colour(&cell: &u8) -> Rgb {
+    match cell {
+        b'#' => Rgb(30, 90, 40),
+        b'+' => Rgb(240, 170, 60),
+        b'O' | b'S' | b'E' => Rgb(220, 40, 40),
+        _ => Rgb(200, 200, 180),
+    }
+}
+
 /**
   Reduces a dense grid to a graph of junctions.
 */
@@ -136,12 +181,18 @@ pub fn reduce_grid(grid: &Grid, start: Coords, end: Coords) -> (Graph, NodeIx, N
     junction_nodes_by_pos.insert(end, end_node);
     let mut stack = Vec::new();
     stack.push((start_node, State { pos: start, way_in: Way::Down, cost: 0 }));
+    // Only drawn when recording frames
+    let mut overlay = aoc::frames::recording().then(|| junction_overlay(grid, start, end));
 
     while let Some((a_node_ix, a @ State { pos: a_pos, cost: a_cost, .. })) = stack.pop() {
         let mut b = a;
+        let mut corridor = Vec::new();
         // Walk corridors
         loop {
             let State { pos: b_pos, cost: b_cost, .. } = b;
+            if overlay.is_some() {
+                corridor.push(b_pos);
+            }
             // Check for cycles and add the back-edge
             match junction_nodes_by_pos.entry(b_pos) {
                 Entry::Vacant(e) => { e.insert(a_node_ix); }
@@ -149,6 +200,7 @@ pub fn reduce_grid(grid: &Grid, start: Coords, end: Coords) -> (Graph, NodeIx, N
                 Entry::Occupied(e) => {
                     let b_node_ix = *e.get();
                     graph.add_edge(a_node_ix, b_node_ix, b_cost - a_cost + 1);
+                    draw_edge(overlay.as_mut(), &corridor, None);
                     break;
                 }
             }
@@ -161,6 +213,7 @@ pub fn reduce_grid(grid: &Grid, start: Coords, end: Coords) -> (Graph, NodeIx, N
                     let b_node_ix = graph.add_node(b_pos);
                     junction_nodes_by_pos.insert(b_pos, b_node_ix);
                     graph.add_edge(a_node_ix, b_node_ix, b_cost - a_cost + 1);
+                    draw_edge(overlay.as_mut(), &corridor, Some(b_pos));
                     stack.extend(neighbours.into_iter().map(|n| (b_node_ix, n)));
                     break;
                 }
@@ -196,9 +249,7 @@ fn walk_paths<S, I, K, IF, KF>(start: S, end: K, mut neighbours: IF, mut key: KF
 
     iter::from_fn(move || {
         loop {
-            let Some((depth, u)) = stack.pop() else {
-                return None;
-            };
+            let (depth, u) = stack.pop()?;
 
             if depth <= path.len() {
                 path.truncate(depth - 1);
@@ -220,7 +271,7 @@ fn walk_paths<S, I, K, IF, KF>(start: S, end: K, mut neighbours: IF, mut key: KF
     })
 }
 
-pub fn part1_longest_path(grid: &Grid, start: Coords, end: Coords) -> u16 {
+pub fn part1_longest_path(grid: &Grid, start: Coords, end: Coords) -> Result<u16, Error> {
     let dfs_iter = walk_paths(
         State { pos: start, way_in: Way::Down, cost: 0 },
         end,
@@ -231,10 +282,10 @@ pub fn part1_longest_path(grid: &Grid, start: Coords, end: Coords) -> u16 {
     dfs_iter
         .filter_map(|State { pos, cost, .. }| (pos == end).then_some(cost))
         .max()
-        .expect("End node not reached")
+        .ok_or_else(|| no_solution("End node not reached"))
 }
 
-pub fn part2_longest_path(graph: &Graph, start: NodeIx, end: NodeIx) -> u16 {
+pub fn part2_longest_path(graph: &Graph, start: NodeIx, end: NodeIx) -> Result<u16, Error> {
     let dfs_iter = walk_paths(
         (start, 0),
         end,
@@ -245,10 +296,10 @@ pub fn part2_longest_path(graph: &Graph, start: NodeIx, end: NodeIx) -> u16 {
     dfs_iter
         .filter_map(|(node, cost)| (node == end).then_some(cost))
         .max()
-        .expect("End node not reached")
+        .ok_or_else(|| no_solution("End node not reached"))
 }
 
-pub fn read_grid<R: Read>(input: R) -> Result<Grid, aoc::error::Error> {
+pub fn read_grid<R: Read>(input: R) -> Result<Grid, Error> {
     let mut r = BufReader::new(input);
     aoc::grid::read_grid(&mut r, None)
 }
@@ -0,0 +1,112 @@
+use aoc::error::{no_solution, Error};
+use aoc::solution::Solution;
+
+use crate::{Coords, Grid, Ordinate, Tile, read_grid, reduce_grid, part1_longest_path, part2_longest_path};
+
+pub fn start(grid: &Grid) -> Result<Coords, Error> {
+    let (r, c) = grid.position(|&tile| tile == Tile::Path).ok_or_else(|| no_solution("No start tile"))?;
+    Ok((r as Ordinate, c as Ordinate))
+}
+
+pub fn end(grid: &Grid) -> Result<Coords, Error> {
+    let (r, c) = grid.rposition(|&tile| tile == Tile::Path).ok_or_else(|| no_solution("No end tile"))?;
+    Ok((r as Ordinate, c as Ordinate))
+}
+
+fn part1(grid: &Grid) -> Result<u16, Error> {
+    let (start, end) = (start(grid)?, end(grid)?);
+    part1_longest_path(grid, start, end)
+}
+
+fn part2(grid: &Grid) -> Result<u16, Error> {
+    let start = start(grid)?;
+    let end = end(grid)?;
+    let (graph, start, end) = reduce_grid(grid, start, end);
+    part2_longest_path(&graph, start, end)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Grid;
+    type Answer1 = u16;
+    type Answer2 = u16;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        read_grid(input.as_bytes())
+    }
+
+    fn part1(grid: &Self::Input) -> Result<u16, aoc::error::Error> {
+        part1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<u16, aoc::error::Error> {
+        part2(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc!{r"
+        #.#####################
+        #.......#########...###
+        #######.#########.#.###
+        ###.....#.>.>.###.#.###
+        ###v#####.#v#.###.#.###
+        ###.>...#.#.#.....#...#
+        ###v###.#.#.#########.#
+        ###...#.#.#.......#...#
+        #####.#.#.#######.#.###
+        #.....#.#.#.......#...#
+        #.#####.#.#.#########v#
+        #.#...#...#...###...>.#
+        #.#.#v#######v###.###v#
+        #...#.>.#...>.>.#.###.#
+        #####v#.#.###v#.#.###.#
+        #.....#...#...#.#.#...#
+        #.#########.###.#.#.###
+        #...###...#...#...#.###
+        ###.###.#.###v#####v###
+        #...#...#.#.>.>.#.>.###
+        #.###.###.#.###.#.#v###
+        #.....###...###...#...#
+        #####################.#
+    "};
+
+    const EXAMPLE_REDUCED: &str = indoc!{r"
+        #.#####################
+        #.......#########...###
+        #######.#########.#.###
+        ###.....#.........#.###
+        ###.#####.###.#####.###
+        ###...................#
+        #####################.#
+    "};
+
+    #[test]
+    fn part1_example() {
+        let grid = read_grid(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part1(&grid).unwrap();
+        assert_eq!(answer, 94);
+    }
+
+    #[test]
+    fn part2_example() {
+        let grid = read_grid(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part2(&grid).unwrap();
+        assert_eq!(answer, 154);
+    }
+
+    #[test]
+    fn part2_example_reduced() {
+        let grid = read_grid(Cursor::new(EXAMPLE_REDUCED)).unwrap();
+        let answer = part2(&grid).unwrap();
+        assert_eq!(answer, 42);
+    }
+}
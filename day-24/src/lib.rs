@@ -0,0 +1,176 @@
+use std::io::{BufRead, BufReader, Read};
+use itertools::Itertools;
+use aoc::error::{aoc_err, no_solution};
+use aoc::parse::AtLine;
+use aoc::parse::combinators::{float, parse_all, position_velocity};
+use aoc::solution::Solution;
+use z3::ast::{Ast, Int, Real};
+
+type Vec2 = [f64; 2];
+type Vec3 = [f64; 3];
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Motion {
+    pos: Vec3,
+    vel: Vec3,
+}
+
+fn read_hailstones<R: Read>(input: R) -> Result<Vec<Motion>, aoc::error::Error> {
+    let lines = BufReader::new(input).lines();
+    let mut result = Vec::new();
+    for (i, line) in lines.enumerate() {
+        let line = line?;
+        if line.is_empty() {
+            continue;
+        }
+
+        let (pos, vel) = parse_all(&line, position_velocity(float)).at_line(i + 1, &line)?;
+        result.push(Motion { pos, vel });
+    }
+
+    Ok(result)
+}
+
+fn line_intersection(a: &Motion, b: &Motion) -> Option<Vec2> {
+    // https://en.wikipedia.org/wiki/Line%E2%80%93line_intersection#Given_two_points_on_each_line_segment
+
+    let [x1, y1, _] = a.pos;
+    let [x2, y2] = [a.pos[0] + a.vel[0], a.pos[1] + a.vel[1]];
+    let [x3, y3, _] = b.pos;
+    let [x4, y4] = [b.pos[0] + b.vel[0], b.pos[1] + b.vel[1]];
+
+    let mut t = (x1 - x3) * (y3 - y4) - (y1 - y3) * (x3 - x4);
+    let mut u = (x1 - x3) * (y1 - y2) - (y1 - y3) * (x1 - x2);
+    let denom = (x1 - x2) * (y3 - y4) - (y1 - y2) * (x3 - x4);
+    if denom == 0.0 {
+        return None;
+    }
+
+    t /= denom;
+    u /= denom;
+    let (px, py) = (x1 + t * (x2 - x1), y1 + t * (y2 - y1));
+    (t > 0.0 && u > 0.0).then_some([px, py])
+}
+
+fn part1(hailstones: &[Motion], min_pos: f64, max_pos: f64) -> u64 {
+    let range = min_pos..=max_pos;
+
+    let total = hailstones.iter().tuple_combinations()
+        .flat_map(|(s1, s2)| line_intersection(s1, s2))
+        .filter(|&[px, py]| range.contains(&px) && range.contains(&py))
+        .count();
+
+    total as u64
+}
+
+fn part2(hailstones: &[Motion]) -> Result<i64, aoc::error::Error> {
+    let ctx = &z3::Context::new(&z3::Config::new());
+    let var = |s: &str| Real::fresh_const(ctx, s);
+    // Only whole numbers that convert to i64 exactly
+    let val = |v: f64| if v.fract() == 0.0 && v.abs() <= 2_u64.pow(53) as f64 {
+        Ok(Int::from_i64(ctx, v as i64).to_real())
+    } else {
+        Err(aoc_err(format!("Can't use {v} as an exact integer")))
+    };
+    let vals = |[x, y, z]: Vec3| -> Result<_, aoc::error::Error> { Ok([val(x)?, val(y)?, val(z)?]) };
+
+    // Rock trajectory
+    let [ref rx, ref ry, ref rz] = ["rx", "ry", "rz"].map(var);
+    let [ref rdx, ref rdy, ref rdz] = ["rdx", "rdy", "rdz"].map(var);
+
+    let zero = val(0.0)?;
+    let solver = z3::Solver::new(ctx);
+
+    // Only 3 hailstones are required to give 9 equations in 9 unknowns:
+    // 6 for the rock position and velocity, and a t for each hailstone impact
+    let Some(hailstones) = hailstones.get(..3) else {
+        return Err(no_solution(format!("Needs 3 hailstones, found {}", hailstones.len())));
+    };
+    for hailstone in hailstones {
+        // Hailstone trajectory
+        let [hx, hy, hz] = vals(hailstone.pos)?;
+        let [hdx, hdy, hdz] = vals(hailstone.vel)?;
+        let t = &var("t");
+
+        for constraint in [
+            t.ge(&zero),
+            (hx + hdx * t)._eq(&(rx + rdx * t)),
+            (hy + hdy * t)._eq(&(ry + rdy * t)),
+            (hz + hdz * t)._eq(&(rz + rdz * t))
+        ] {
+            solver.assert(&constraint);
+        }
+    }
+
+    if solver.check() != z3::SatResult::Sat {
+        return Err("Unsatisfiable".into());
+    };
+
+    let model = solver.get_model().ok_or("No model")?;
+    let result = model.eval(&(rx + ry + rz), true)
+        .and_then(|r| r.as_real())
+        .map(|(n, d)| n as f64 / d as f64)
+        .ok_or("Failed to evaluate")?;
+
+    Ok(result.round() as i64)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Vec<Motion>;
+    type Answer1 = u64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        read_hailstones(input.as_bytes())
+    }
+
+    fn part1(hailstones: &Self::Input) -> Result<u64, aoc::error::Error> {
+        Ok(part1(hailstones, 2e14, 4e14))
+    }
+
+    fn part2(hailstones: &Self::Input) -> Result<i64, aoc::error::Error> {
+        part2(hailstones)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {r"
+        19, 13, 30 @ -2,  1, -2
+        18, 19, 22 @ -1, -1, -2
+        20, 25, 34 @ -2, -2, -4
+        12, 31, 28 @ -1, -2, -1
+        20, 19, 15 @  1, -5, -3
+    "};
+
+    #[test]
+    fn part1_example() {
+        let hailstones = read_hailstones(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part1(&hailstones, 7.0, 27.0);
+        assert_eq!(answer, 2);
+    }
+
+    #[test]
+    fn part2_example() {
+        let hailstones = read_hailstones(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part2(&hailstones).unwrap();
+        assert_eq!(answer, 47);
+    }
+
+    #[test]
+    fn unusable_hailstones() {
+        let hailstones = read_hailstones(Cursor::new(&EXAMPLE[..48])).unwrap();
+        assert!(matches!(part2(&hailstones), Err(aoc::error::Error::NoSolution(_))));
+
+        let hailstones = read_hailstones(Cursor::new(EXAMPLE.replace("19, 13, 30", "19, 1e300, 30"))).unwrap();
+        assert!(part2(&hailstones).is_err());
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read};
+
+use itertools::Itertools;
+use nom::character::complete::space1;
+use nom::multi::separated_list1;
+use petgraph::Undirected;
+use petgraph::data::DataMap;
+use petgraph::graph::EdgeIndex;
+use rand::Rng;
+use rayon::prelude::*;
+
+use aoc::error::{aoc_err, no_solution, Error};
+use aoc::parse::AtLine;
+use aoc::parse::combinators::{header, parse_all, word};
+use aoc::solution::{NoAnswer, Solution};
+
+type Graph = petgraph::Graph<u32, (), Undirected>;
+
+// Each trial finds the minimum cut with probability at least 2/n² but, for puzzle inputs,
+// far more often than that. Without a cut of 3 wires, every trial is run.
+const KARGER_TRIALS: usize = 1000;
+
+fn read_graph<R: Read>(input: R) -> Result<Graph, aoc::error::Error> {
+    let lines = BufReader::new(input).lines();
+    let mut graph = Graph::new_undirected();
+    let mut nodes = HashMap::new();
+    for (i, line) in lines.enumerate() {
+        let line = line?;
+        if line.is_empty() {
+            continue;
+        }
+
+        let (lhs, others) = parse_all(&line, header(separated_list1(space1, word)))
+            .at_line(i + 1, &line)?;
+
+        let mut add_node = |g: &mut Graph, n: &str| *nodes.entry(n.to_string())
+            .or_insert_with(|| {
+                g.add_node(1)
+            });
+
+        let n1 = add_node(&mut graph, lhs);
+        for rhs in others {
+            let n2 = add_node(&mut graph, rhs);
+            graph.update_edge(n1, n2, ());
+        }
+    }
+
+    Ok(graph)
+}
+
+fn contract_edge(graph: &mut Graph, edge_ix: EdgeIndex) {
+    let (a, b) = graph.edge_endpoints(edge_ix)
+        .unwrap_or_else(|| panic!("Bad edge_ix: {edge_ix:?}"));
+
+    let b_weight = *graph.node_weight(b)
+        .unwrap_or_else(|| panic!("Bad node_ix: {b:?}"));
+
+    let mut walker = graph.neighbors(b).detach();
+    while let Some(b_neigh) = walker.next_node(graph) {
+        if b_neigh != a && b_neigh != b {
+            graph.add_edge(a, b_neigh, ());
+        }
+    }
+
+    if let Some(a_weight) = graph.node_weight_mut(a) {
+        *a_weight += b_weight;
+    }
+
+    graph.remove_node(b);
+}
+
+fn contracted_graph(graph: &Graph) -> Graph {
+    let mut graph = graph.clone();
+    let mut rng = rand::thread_rng();
+    // Stops early, with no edges left, if the graph is disconnected
+    while graph.node_count() > 2 && graph.edge_count() > 0 {
+        let edge_ix = rng.gen_range(0..graph.edge_count());
+        contract_edge(&mut graph, EdgeIndex::new(edge_ix));
+    }
+
+    graph
+}
+
+fn part1(original_graph: &Graph) -> Result<u32, Error> {
+    // https://en.wikipedia.org/wiki/Karger%27s_algorithm
+    // Repeatedly contract random edges until only 2 nodes remain
+    let cut = (0..KARGER_TRIALS).into_par_iter()
+        .map(|_| contracted_graph(original_graph))
+        .find_any(|g| g.edge_count() <= 3)
+        .ok_or_else(|| no_solution(format!("No cut of 3 wires found in {KARGER_TRIALS} trials")))?;
+    let answer: (u32, u32) = cut.node_weights().copied().collect_tuple()
+        .ok_or_else(|| no_solution("Expected exactly 2 connected components in the graph"))?;
+
+    Ok(answer.0 * answer.1)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const PARTS: u8 = 1;
+
+    type Input = Graph;
+    type Answer1 = u32;
+    type Answer2 = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::error::Error> {
+        read_graph(input.as_bytes())
+    }
+
+    fn part1(graph: &Self::Input) -> Result<u32, aoc::error::Error> {
+        part1(graph)
+    }
+
+    fn part2(_graph: &Self::Input) -> Result<NoAnswer, aoc::error::Error> {
+        Err(aoc_err("Day 25 has no part 2"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {r"
+        jqt: rhn xhk nvd
+        rsh: frs pzl lsr
+        xhk: hfx
+        cmg: qnr nvd lhk bvb
+        rhn: xhk bvb hfx
+        bvb: xhk hfx
+        pzl: lsr hfx nvd
+        qnr: nvd
+        ntq: jqt hfx bvb xhk
+        nvd: lhk
+        lsr: lhk
+        rzs: qnr cmg lsr rsh
+        frs: qnr lhk lsr
+    "};
+
+    #[test]
+    fn part1_example() {
+        let graph = read_graph(Cursor::new(EXAMPLE)).unwrap();
+        let answer = part1(&graph).unwrap();
+        assert_eq!(answer, 54);
+    }
+
+    #[test]
+    fn disconnected_graph() {
+        let graph = read_graph(Cursor::new("a: b\nc: d\ne: f\n")).unwrap();
+        assert!(part1(&graph).is_err());
+    }
+
+    #[test]
+    fn no_three_wire_cut() {
+        // Every node of a complete graph on 5 nodes has 4 wires
+        let graph = read_graph(Cursor::new("a: b c d e
+b: c d e
+c: d e
+d: e
+")).unwrap();
+        assert!(part1(&graph).is_err());
+    }
+}